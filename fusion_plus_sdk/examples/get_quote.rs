@@ -1,5 +1,7 @@
-use alloy::primitives::{Address, U256};
-use fusion_plus_sdk::{addresses::usdc, api::Api, chain_id::ChainId, quote::QuoteRequest};
+use alloy::primitives::Address;
+use fusion_plus_sdk::{
+    addresses::usdc, api::Api, chain_id::ChainId, quote::QuoteRequest, tokens::TokenRegistry,
+};
 
 #[tokio::main]
 async fn main() -> fusion_plus_sdk::Result<()> {
@@ -10,13 +12,12 @@ async fn main() -> fusion_plus_sdk::Result<()> {
         std::env::var("ONEINCH_API_KEY").expect("ONEINCH_API_KEY not set in .env file"),
     );
 
+    let amount = TokenRegistry::with_defaults().parse_amount(ChainId::Optimism, "1 USDC")?;
     let result = api
-        .get_quote(&QuoteRequest::new(
-            ChainId::Optimism,
+        .get_quote(&QuoteRequest::from_token_amount(
+            amount,
             ChainId::Arbitrum,
-            usdc(ChainId::Optimism),
             usdc(ChainId::Arbitrum),
-            U256::from(1e6),
             true,
             Address::ZERO,
         ))
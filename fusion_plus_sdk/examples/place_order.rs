@@ -1,22 +1,19 @@
 use std::str::FromStr;
 
-use alloy::{
-    primitives::{B256, U64, U256, keccak256},
-    signers::{Signer, local::PrivateKeySigner},
-};
+use alloy::{primitives::B256, signers::local::PrivateKeySigner};
+use chrono::Utc;
 use fusion_plus_sdk::{
-    addresses::{get_limit_order_contract_address, usdc},
+    addresses::usdc,
     api::Api,
     chain_id::ChainId,
-    cross_chain_order::{CrossChainOrderParams, Fee, PreparedOrder},
+    cross_chain_order::{CrossChainOrderParams, PreparedOrder},
     hash_lock::HashLock,
+    limit::permit::Eip2612Permit,
     multichain_address::MultichainAddress,
     quote::QuoteRequest,
     relayer_request::RelayerRequest,
-    utils::{
-        alloy::{ERC20, create_provider},
-        random::get_random_bytes32,
-    },
+    tokens::TokenRegistry,
+    utils::{alloy::create_provider, random::get_random_bytes32},
 };
 
 #[tokio::main]
@@ -33,12 +30,11 @@ pub async fn main() -> fusion_plus_sdk::Result<()> {
     )
     .unwrap();
 
-    let quote_request = QuoteRequest::new(
-        ChainId::Arbitrum,
+    let amount = TokenRegistry::with_defaults().parse_amount(ChainId::Arbitrum, "1 USDC")?;
+    let quote_request = QuoteRequest::from_token_amount(
+        amount,
         ChainId::Optimism,
-        usdc(ChainId::Arbitrum),
         usdc(ChainId::Optimism),
-        U256::from(1e6),
         true,
         wallet.address(),
     );
@@ -47,52 +43,28 @@ pub async fn main() -> fusion_plus_sdk::Result<()> {
     let quote_result = api.get_quote(&quote_request).await?;
     println!("Quote Result: {quote_result:#?}");
 
-    let arb = create_provider(ChainId::Arbitrum, wallet.clone());
+    let arb = create_provider(ChainId::Arbitrum, wallet.clone())?;
 
-    let usdc_arb = ERC20::new(usdc(ChainId::Arbitrum).as_raw(), &arb);
-    let spender = get_limit_order_contract_address(quote_request.src_chain_id);
-    let allowance = usdc_arb
-        .allowance(wallet.address(), spender.as_raw())
-        .call()
-        .await
-        .unwrap();
-    if allowance < quote_request.src_amount {
-        println!("Approving tokens for escrow factory...");
-        usdc_arb
-            .approve(spender.as_raw(), U256::MAX)
-            .send()
-            .await
-            .unwrap()
-            .watch()
-            .await
-            .unwrap();
-        println!("Token approved.");
-    } else {
-        println!("Token already approved for escrow factory.");
-    }
+    // USDC supports EIP-2612, signing a permit saves the approve transaction
+    let deadline = Utc::now().timestamp() as u64 + 3600;
+    let permit = Eip2612Permit::fetch(
+        &arb,
+        ChainId::Arbitrum,
+        usdc(ChainId::Arbitrum).as_raw(),
+        wallet.address(),
+        quote_request.src_amount,
+        deadline,
+    )
+    .await?
+    .sign(&wallet)
+    .await?;
+    println!("Permit signed.");
 
     let secrets_count = quote_result.recommended_preset().secrets_count;
     let secrets: Vec<B256> = (0..secrets_count).map(|_| get_random_bytes32()).collect();
     let secret_hashes: Vec<B256> = secrets.iter().map(HashLock::hash_secret).collect();
 
-    let hash_lock = if secrets_count == 1 {
-        HashLock::for_single_fill(&secrets[0])
-    } else {
-        HashLock::for_multiple_fills(
-            secrets
-                .iter()
-                .enumerate()
-                .map(|(i, s)| {
-                    let mut encoded = [0u8; 36];
-                    encoded[0..4].copy_from_slice(U64::from(i).to_be_bytes::<4>().as_ref());
-                    encoded[4..36].copy_from_slice(s.as_ref());
-
-                    keccak256(encoded)
-                })
-                .collect(),
-        )
-        .unwrap()
-    };
+    let hash_lock = HashLock::from_secrets(&secrets)?;
 
     let order = PreparedOrder::from_quote(
         &quote_request,
@@ -100,31 +72,22 @@ pub async fn main() -> fusion_plus_sdk::Result<()> {
         CrossChainOrderParams {
             dst_address: MultichainAddress::from_raw(wallet.address()),
             hash_lock,
-            secret_hashes: secret_hashes.clone(),
-            fee: Some(Fee {
-                taking_fee_bps: 100,
-                taking_fee_receiver: MultichainAddress::ZERO,
-            }),
+            secret_hashes,
+            fee: None,
+            resolver_fee: None,
             preset: None,
+            epoch: None,
+            permit: Some(permit),
         },
     )
     .unwrap();
 
     println!("Order created: {order:#?}");
 
-    let order_hash = order.eip712_signing_hash();
-    let signature = wallet.sign_hash(&order_hash).await.unwrap();
+    let order_hash = order.eip712_signing_hash()?;
+    let signature = order.sign(&wallet).await?;
 
-    let rr = RelayerRequest::from_prepared_order(
-        &order,
-        &signature,
-        quote_result.quote_id.clone().unwrap(),
-        if secret_hashes.len() == 1 {
-            None
-        } else {
-            Some(secret_hashes)
-        },
-    );
+    let rr = RelayerRequest::try_from_prepared_order(&order, &signature)?;
 
     println!("Relayer Request: {rr:#?}");
     api.submit_order(rr).await?;
@@ -1,14 +1,14 @@
 use std::str::FromStr;
 
 use alloy::{
-    primitives::{B256, U64, U256, keccak256},
-    signers::{Signer, local::PrivateKeySigner},
+    primitives::{B256, U256},
+    signers::local::PrivateKeySigner,
 };
 use fusion_plus_sdk::{
     addresses::{get_limit_order_contract_address, usdc},
     api::Api,
     chain_id::ChainId,
-    cross_chain_order::{CrossChainOrderParams, Fee, PreparedOrder},
+    cross_chain_order::{CrossChainOrderParams, PreparedOrder},
     hash_lock::HashLock,
     multichain_address::MultichainAddress,
     quote::{
@@ -16,6 +16,7 @@ use fusion_plus_sdk::{
         preset::{CustomPreset, CustomPresetPoint, PresetType},
     },
     relayer_request::RelayerRequest,
+    tokens::TokenRegistry,
     utils::{
         alloy::{ERC20, create_provider},
         random::get_random_bytes32,
@@ -36,12 +37,17 @@ pub async fn main() -> fusion_plus_sdk::Result<()> {
     )
     .unwrap();
 
-    let quote_request = QuoteRequest::new(
-        ChainId::Arbitrum,
+    let registry = TokenRegistry::with_defaults();
+    let amount = registry.parse_amount(ChainId::Arbitrum, "1 USDC")?;
+    let dst_usdc = |value: &str| {
+        registry
+            .parse_amount(ChainId::Optimism, &format!("{value} USDC"))
+            .map(|amount| amount.amount)
+    };
+    let quote_request = QuoteRequest::from_token_amount(
+        amount,
         ChainId::Optimism,
-        usdc(ChainId::Arbitrum),
         usdc(ChainId::Optimism),
-        U256::from(1e6),
         true,
         wallet.address(),
     );
@@ -52,19 +58,19 @@ pub async fn main() -> fusion_plus_sdk::Result<()> {
             &quote_request,
             CustomPreset {
                 auction_duration: 600,
-                auction_start_amount: U256::from(2e6),
-                auction_end_amount: U256::from(6e5),
+                auction_start_amount: dst_usdc("2")?,
+                auction_end_amount: dst_usdc("0.6")?,
                 points: Some(vec![
                     CustomPresetPoint {
-                        to_token_amount: U256::from(15e5),
+                        to_token_amount: dst_usdc("1.5")?,
                         delay: 30,
                     },
                     CustomPresetPoint {
-                        to_token_amount: U256::from(12e5),
+                        to_token_amount: dst_usdc("1.2")?,
                         delay: 60,
                     },
                     CustomPresetPoint {
-                        to_token_amount: U256::from(7e5),
+                        to_token_amount: dst_usdc("0.7")?,
                         delay: 90,
                     },
                 ]),
@@ -73,7 +79,7 @@ pub async fn main() -> fusion_plus_sdk::Result<()> {
         .await?;
     println!("Quote Result: {quote_result:#?}");
 
-    let arb = create_provider(ChainId::Arbitrum, wallet.clone());
+    let arb = create_provider(ChainId::Arbitrum, wallet.clone())?;
 
     let usdc_arb = ERC20::new(usdc(ChainId::Arbitrum).as_raw(), &arb);
     let spender = get_limit_order_contract_address(quote_request.src_chain_id);
@@ -101,24 +107,7 @@ pub async fn main() -> fusion_plus_sdk::Result<()> {
     let secrets: Vec<B256> = (0..secrets_count).map(|_| get_random_bytes32()).collect();
     let secret_hashes: Vec<B256> = secrets.iter().map(HashLock::hash_secret).collect();
 
-    let hash_lock = if secrets_count == 1 {
-        HashLock::for_single_fill(&secrets[0])
-    } else {
-        HashLock::for_multiple_fills(
-            secrets
-                .iter()
-                .enumerate()
-                .map(|(i, s)| {
-                    let mut encoded = [0u8; 36];
-                    encoded[0..4].copy_from_slice(U64::from(i).to_be_bytes::<4>().as_ref());
-                    encoded[4..36].copy_from_slice(s.as_ref());
-
-                    keccak256(encoded)
-                })
-                .collect(),
-        )
-        .unwrap()
-    };
+    let hash_lock = HashLock::from_secrets(&secrets)?;
 
     let order = PreparedOrder::from_quote(
         &quote_request,
@@ -126,31 +115,22 @@ pub async fn main() -> fusion_plus_sdk::Result<()> {
         CrossChainOrderParams {
             dst_address: MultichainAddress::from_raw(wallet.address()),
             hash_lock,
-            secret_hashes: secret_hashes.clone(),
-            fee: Some(Fee {
-                taking_fee_bps: 100,
-                taking_fee_receiver: MultichainAddress::ZERO,
-            }),
+            secret_hashes,
+            fee: None,
+            resolver_fee: None,
             preset: Some(PresetType::Custom),
+            epoch: None,
+            permit: None,
         },
     )
     .unwrap();
 
     println!("Order created: {order:#?}");
 
-    let order_hash = order.eip712_signing_hash();
-    let signature = wallet.sign_hash(&order_hash).await.unwrap();
-
-    let rr = RelayerRequest::from_prepared_order(
-        &order,
-        &signature,
-        quote_result.quote_id.clone().unwrap(),
-        if secret_hashes.len() == 1 {
-            None
-        } else {
-            Some(secret_hashes)
-        },
-    );
+    let order_hash = order.eip712_signing_hash()?;
+    let signature = order.sign(&wallet).await?;
+
+    let rr = RelayerRequest::try_from_prepared_order(&order, &signature)?;
 
     println!("Relayer Request: {rr:#?}");
     api.submit_order(rr).await?;
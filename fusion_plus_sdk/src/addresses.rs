@@ -1,62 +1,94 @@
-use crate::{chain_id::ChainId, multichain_address::MultichainAddress};
+use crate::{
+    chain_id::ChainId, chain_metadata::ChainMetadata, multichain_address::MultichainAddress,
+};
+
+fn deployment(
+    chain_id: ChainId,
+    address: impl Fn(&ChainMetadata) -> Option<&'static str>,
+) -> crate::Result<MultichainAddress> {
+    address(chain_id.metadata())
+        .ok_or(crate::Error::UnsupportedChainId(chain_id as u32))?
+        .parse()
+}
+
+pub fn try_get_limit_order_contract_address(chain_id: ChainId) -> crate::Result<MultichainAddress> {
+    deployment(chain_id, |metadata| metadata.limit_order_protocol)
+}
 
-// TODO update this
 pub fn get_limit_order_contract_address(chain_id: ChainId) -> MultichainAddress {
-    match chain_id {
-        ChainId::Ethereum => "0x111111125421ca6dc452d289314280a0f8842a65"
-            .parse()
-            .unwrap(),
-        ChainId::Optimism => "0x111111125421ca6dc452d289314280a0f8842a65"
-            .parse()
-            .unwrap(),
-        ChainId::Arbitrum => "0x111111125421ca6dc452d289314280a0f8842a65"
-            .parse()
-            .unwrap(),
-        ChainId::Tron => todo!(),
-    }
+    try_get_limit_order_contract_address(chain_id).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn try_get_true_erc20_address(chain_id: ChainId) -> crate::Result<MultichainAddress> {
+    deployment(chain_id, |metadata| metadata.true_erc20)
 }
 
 pub fn get_true_erc20_address(chain_id: ChainId) -> MultichainAddress {
-    match chain_id {
-        ChainId::Ethereum => "0xda0000d4000015a526378bb6fafc650cea5966f8"
-            .parse()
-            .unwrap(),
-        ChainId::Optimism => "0xda0000d4000015a526378bb6fafc650cea5966f8"
-            .parse()
-            .unwrap(),
-        ChainId::Arbitrum => "0xda0000d4000015a526378bb6fafc650cea5966f8"
-            .parse()
-            .unwrap(),
-        ChainId::Tron => todo!(),
-    }
+    try_get_true_erc20_address(chain_id).unwrap_or_else(|e| panic!("{e}"))
+}
+
+// Wrapper of the native currency, used as maker asset when swapping from the native currency
+pub fn try_get_wrapped_native_address(chain_id: ChainId) -> crate::Result<MultichainAddress> {
+    deployment(chain_id, |metadata| metadata.wrapped_native)
+}
+
+pub fn get_wrapped_native_address(chain_id: ChainId) -> MultichainAddress {
+    try_get_wrapped_native_address(chain_id).unwrap_or_else(|e| panic!("{e}"))
 }
 
 pub fn usdc(chain_id: ChainId) -> MultichainAddress {
-    match chain_id {
-        ChainId::Ethereum => "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
-            .parse()
-            .unwrap(),
-        ChainId::Optimism => "0x0b2C639c533813f4Aa9D7837CAf62653d097Ff85"
-            .parse()
-            .unwrap(),
-        ChainId::Arbitrum => "0xaf88d065e77c8cC2239327C5EDb3A432268e5831"
-            .parse()
-            .unwrap(),
-        ChainId::Tron => todo!(),
-    }
+    deployment(chain_id, |metadata| metadata.usdc).unwrap_or_else(|e| panic!("{e}"))
 }
 
 pub fn usdt(chain_id: ChainId) -> MultichainAddress {
-    match chain_id {
-        ChainId::Ethereum => "0xdAC17F958D2ee523a2206206994597C13D831ec7"
-            .parse()
-            .unwrap(),
-        ChainId::Optimism => "0x94b008aA00579c1307B0EF2c499aD98a8ce58e58"
-            .parse()
-            .unwrap(),
-        ChainId::Arbitrum => "0xFd086bC7CD5C481DCC9C85ebE478A1C0b69FCbb9"
-            .parse()
-            .unwrap(),
-        ChainId::Tron => todo!(),
+    deployment(chain_id, |metadata| metadata.usdt).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn try_get_permit2_contract_address(chain_id: ChainId) -> crate::Result<MultichainAddress> {
+    deployment(chain_id, |metadata| metadata.permit2)
+}
+
+pub fn get_permit2_contract_address(chain_id: ChainId) -> MultichainAddress {
+    try_get_permit2_contract_address(chain_id).unwrap_or_else(|e| panic!("{e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tron_addresses() {
+        for address in [
+            usdc(ChainId::Tron),
+            usdt(ChainId::Tron),
+            get_wrapped_native_address(ChainId::Tron),
+        ] {
+            assert!(matches!(address, MultichainAddress::Tron { .. }));
+            assert_eq!(address.get_chain_id(), Some(ChainId::Tron));
+        }
+        assert_eq!(
+            usdt(ChainId::Tron).to_string(),
+            "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"
+        );
+
+        assert!(matches!(
+            try_get_limit_order_contract_address(ChainId::Tron),
+            Err(crate::Error::UnsupportedChainId(728126428))
+        ));
+        assert!(try_get_true_erc20_address(ChainId::Tron).is_err());
+        assert!(try_get_permit2_contract_address(ChainId::Tron).is_err());
+    }
+
+    #[test]
+    fn test_evm_addresses() {
+        assert_eq!(
+            get_limit_order_contract_address(ChainId::Base),
+            get_limit_order_contract_address(ChainId::Ethereum)
+        );
+        assert_eq!(
+            get_wrapped_native_address(ChainId::Unichain),
+            get_wrapped_native_address(ChainId::Optimism)
+        );
+        assert!(try_get_permit2_contract_address(ChainId::ZkSync).is_err());
     }
 }
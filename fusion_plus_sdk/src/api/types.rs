@@ -10,17 +10,17 @@ use crate::{
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PaginationMeta {
-    total_items: usize,
-    items_per_page: usize,
-    total_pages: usize,
-    current_page: usize,
+    pub total_items: usize,
+    pub items_per_page: usize,
+    pub total_pages: usize,
+    pub current_page: usize,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PaginationOutput<Inner> {
-    meta: PaginationMeta,
-    items: Vec<Inner>,
+    pub meta: PaginationMeta,
+    pub items: Vec<Inner>,
 }
 
 #[derive(Debug)]
@@ -224,28 +224,28 @@ impl_paginated!(ActiveOrdersRequestParams);
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActiveOrder {
-    quote_id: String,
-    order_hash: B256,
-    signature: Bytes,
-    deadline: String,
-    auction_start_date: String,
-    auction_end_date: String,
-    remaining_maker_amount: U256,
-    maker_balance: U256,
-    maker_allowance: U256,
-    order: LimitOrderV4,
-    extension: Bytes,
-    src_chain_id: ChainId,
-    dst_chain_id: ChainId,
-    is_maker_contract: bool,
-    secret_hashes: Option<Vec<B256>>,
-    fills: Vec<FillInfo>,
+    pub quote_id: String,
+    pub order_hash: B256,
+    pub signature: Bytes,
+    pub deadline: String,
+    pub auction_start_date: String,
+    pub auction_end_date: String,
+    pub remaining_maker_amount: U256,
+    pub maker_balance: U256,
+    pub maker_allowance: U256,
+    pub order: LimitOrderV4,
+    pub extension: Bytes,
+    pub src_chain_id: ChainId,
+    pub dst_chain_id: ChainId,
+    pub is_maker_contract: bool,
+    pub secret_hashes: Option<Vec<B256>>,
+    pub fills: Vec<FillInfo>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FillInfo {
-    tx_hash: B256,
+    pub tx_hash: B256,
 }
 
 #[serde_with::skip_serializing_none]
@@ -1,11 +1,21 @@
-use alloy::primitives::U256;
+use alloy::{primitives::U256, providers::Provider};
+use chrono::Utc;
+use serde::Serialize;
 
 use crate::{
+    api::types::ActiveOrder,
+    escrow_extension::EscrowExtension,
     fusion::{
         auction_details::{AuctionDetails, AuctionPoint},
+        fusion_extension::FusionExtension,
         settlement_post_interaction::SettlementPostInteractionData,
     },
+    limit::extension::Extension,
     quote::GasCostConfig,
+    utils::{
+        alloy::get_latest_block_base_fee,
+        bps::{FEE_BASE, add_ratio_to_amount},
+    },
 };
 
 pub const RATE_BUMP_DENOMINATOR: u64 = 10_000_000; // 100%
@@ -21,10 +31,40 @@ pub struct AuctionCalculator {
     pub gas_cost: GasCostConfig,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurveSample {
+    pub timestamp: u64,
+    pub rate_bump: u64,
+    pub taking_amount: U256,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct AuctionCurve {
+    pub samples: Vec<CurveSample>,
+}
+
+impl AuctionCurve {
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("timestamp,rate_bump,taking_amount\n");
+        for sample in &self.samples {
+            csv.push_str(&format!(
+                "{},{},{}\n",
+                sample.timestamp, sample.rate_bump, sample.taking_amount
+            ));
+        }
+        csv
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.samples).expect("curve samples are always serializable")
+    }
+}
+
 impl AuctionCalculator {
     pub fn from_auction_data(data: SettlementPostInteractionData, details: AuctionDetails) -> Self {
         AuctionCalculator {
-            start_time: data.resolving_start_time,
+            start_time: details.start_time,
             duration: details.duration,
             initial_rate_bump: details.initial_rate_bump,
             points: details.points,
@@ -33,6 +73,22 @@ impl AuctionCalculator {
         }
     }
 
+    pub fn from_extension(extension: &Extension) -> crate::Result<Self> {
+        Ok(Self::from_fusion_extension(
+            FusionExtension::from_extension(extension.clone())?,
+        ))
+    }
+
+    // Active orders are cross chain orders, their post interaction carries the escrow extra data
+    pub fn from_active_order(order: &ActiveOrder) -> crate::Result<Self> {
+        let extension = EscrowExtension::decode_from(order.extension.clone())?;
+        Ok(Self::from_fusion_extension(extension.fusion_extension))
+    }
+
+    fn from_fusion_extension(extension: FusionExtension) -> Self {
+        Self::from_auction_data(extension.post_interaction_data, extension.auction_details)
+    }
+
     pub fn finish_time(&self) -> u64 {
         self.start_time + self.duration
     }
@@ -47,6 +103,52 @@ impl AuctionCalculator {
         numerator.div_ceil(denominator) // rounding up
     }
 
+    pub fn calc_auction_taking_amount_with_fee(&self, taking_amount: U256, rate: u64) -> U256 {
+        let auction_taking_amount = Self::calc_auction_taking_amount(taking_amount, rate);
+
+        if self.taker_fee_ratio == 0 {
+            return auction_taking_amount;
+        }
+
+        add_ratio_to_amount(auction_taking_amount, self.taker_fee_ratio)
+    }
+
+    pub fn taking_amount_at(&self, taking_amount: U256, time: u64, block_base_fee: U256) -> U256 {
+        let rate_bump = self.calc_rate_bump(time, block_base_fee);
+        self.calc_auction_taking_amount_with_fee(taking_amount, rate_bump)
+    }
+
+    pub fn current_taking_amount(&self, taking_amount: U256, block_base_fee: U256) -> U256 {
+        self.taking_amount_at(taking_amount, Utc::now().timestamp() as u64, block_base_fee)
+    }
+
+    // Uses the timestamp and base fee of the latest block of the order's src chain
+    pub async fn latest_block_taking_amount(
+        &self,
+        provider: &impl Provider,
+        taking_amount: U256,
+    ) -> crate::Result<U256> {
+        let (timestamp, block_base_fee) = get_latest_block_base_fee(provider).await?;
+        Ok(self.taking_amount_at(taking_amount, timestamp, block_base_fee))
+    }
+
+    pub fn simulate_base_fees(
+        &self,
+        taking_amount: U256,
+        time: u64,
+        block_base_fees: &[U256],
+    ) -> Vec<(U256, U256)> {
+        block_base_fees
+            .iter()
+            .map(|block_base_fee| {
+                (
+                    *block_base_fee,
+                    self.taking_amount_at(taking_amount, time, *block_base_fee),
+                )
+            })
+            .collect()
+    }
+
     pub fn base_fee_to_gas_price_estimate(base_fee: u64) -> u64 {
         base_fee / 1_000_000
     }
@@ -62,7 +164,146 @@ impl AuctionCalculator {
         auction_bump.saturating_sub(gas_bump)
     }
 
-    fn get_gas_price_bump(&self, block_base_fee: U256) -> u64 {
+    /// Samples the auction every `step` seconds from start to finish, both ends included.
+    /// When `block_base_fee` is given the gas bump for that base fee is subtracted from the rate.
+    /// Taking amounts include the integrator fee, same as `taking_amount_at`.
+    pub fn curve(
+        &self,
+        taking_amount: U256,
+        step: u64,
+        block_base_fee: Option<U256>,
+    ) -> AuctionCurve {
+        let block_base_fee = block_base_fee.unwrap_or_default();
+        let finish_time = self.finish_time();
+
+        let mut samples = vec![];
+        let mut time = self.start_time;
+        loop {
+            let rate_bump = self.calc_rate_bump(time, block_base_fee);
+            samples.push(CurveSample {
+                timestamp: time,
+                rate_bump,
+                taking_amount: self.calc_auction_taking_amount_with_fee(taking_amount, rate_bump),
+            });
+
+            if time >= finish_time {
+                break;
+            }
+            time = time.saturating_add(step.max(1)).min(finish_time);
+        }
+
+        AuctionCurve { samples }
+    }
+
+    /// Earliest timestamp at which the auction taking amount for `taking_amount`, including the
+    /// integrator fee, drops to `target` or below. `None` if `target` is never reached, i.e. it is below the auction end amount.
+    pub fn time_for_taking_amount(
+        &self,
+        taking_amount: U256,
+        target: U256,
+        block_base_fee: U256,
+    ) -> Option<u64> {
+        // highest auction taking amount which still fits into target once the fee is added
+        let max_auction_amount = self.max_amount_before_fee(target);
+        if taking_amount.is_zero() || max_auction_amount < taking_amount {
+            return None;
+        }
+
+        // highest rate bump for which `calc_auction_taking_amount` still fits into target
+        let max_rate_bump = max_auction_amount * U256::from(RATE_BUMP_DENOMINATOR) / taking_amount
+            - U256::from(RATE_BUMP_DENOMINATOR);
+        let threshold = max_rate_bump
+            .saturating_add(U256::from(self.get_gas_price_bump(block_base_fee)))
+            .saturating_to::<u64>();
+
+        if self.initial_rate_bump <= threshold {
+            return Some(self.start_time);
+        }
+
+        let finish_time = self.finish_time();
+        let mut current_point_time = self.start_time;
+        let mut current_rate_bump = self.initial_rate_bump;
+
+        let mut next_point_time = self.start_time;
+        let points = self
+            .points
+            .iter()
+            .map(|point| {
+                next_point_time += point.delay;
+                (next_point_time, point.coefficient)
+            })
+            .chain(std::iter::once((finish_time, 0)));
+
+        for (next_point_time, next_rate_bump) in points {
+            if current_point_time >= finish_time {
+                break;
+            }
+
+            if let Some(elapsed) = Self::first_elapsed_at_or_below(
+                next_point_time - current_point_time,
+                current_rate_bump,
+                next_rate_bump,
+                threshold,
+            ) {
+                return Some((current_point_time + elapsed).min(finish_time));
+            }
+
+            current_point_time = next_point_time;
+            current_rate_bump = next_rate_bump;
+        }
+
+        // the rate bump is 0 once the auction is finished
+        Some(finish_time)
+    }
+
+    // Largest amount for which `add_ratio_to_amount` with the taker fee is at most `target`
+    fn max_amount_before_fee(&self, target: U256) -> U256 {
+        if self.taker_fee_ratio == 0 {
+            return target;
+        }
+
+        let mut amount =
+            target * U256::from(FEE_BASE) / U256::from(FEE_BASE + self.taker_fee_ratio);
+        // the fee is rounded down, so the estimate can be short by a unit
+        while add_ratio_to_amount(amount + U256::from(1), self.taker_fee_ratio) <= target {
+            amount += U256::from(1);
+        }
+
+        amount
+    }
+
+    // Smallest `elapsed` in 1..=time_diff for which the interpolation used by `get_auction_bump`
+    // is at most `threshold`.
+    fn first_elapsed_at_or_below(
+        time_diff: u64,
+        from_rate_bump: u64,
+        to_rate_bump: u64,
+        threshold: u64,
+    ) -> Option<u64> {
+        if time_diff == 0 {
+            return None;
+        }
+
+        let (time_diff, from, to) = (
+            time_diff as i128,
+            from_rate_bump as i128,
+            to_rate_bump as i128,
+        );
+        // floor((elapsed * to + (time_diff - elapsed) * from) / time_diff) <= threshold
+        let limit = (threshold as i128 + 1) * time_diff - 1;
+        let value_at = |elapsed: i128| time_diff * from + elapsed * (to - from);
+
+        let elapsed = if to < from {
+            let (numerator, denominator) = (time_diff * from - limit, from - to);
+            ((numerator + denominator - 1) / denominator).max(1)
+        } else {
+            1
+        };
+
+        (elapsed <= time_diff && value_at(elapsed) <= limit).then_some(elapsed as u64)
+    }
+
+    pub fn get_gas_price_bump(&self, block_base_fee: U256) -> u64 {
         if self.gas_cost.gas_bump_estimate == 0
             || self.gas_cost.gas_price_estimate.is_zero()
             || block_base_fee == U256::ZERO
@@ -70,10 +311,11 @@ impl AuctionCalculator {
             return 0;
         }
 
-        (((U256::from(self.gas_cost.gas_bump_estimate) * block_base_fee)
-            / self.gas_cost.gas_price_estimate)
+        // gas_price_estimate is in 1/1000 gwei while the base fee is in wei
+        (U256::from(self.gas_cost.gas_bump_estimate).saturating_mul(block_base_fee)
+            / self.gas_cost.gas_price_estimate
             / U256::from(1_000_000))
-        .to::<u64>()
+        .saturating_to::<u64>()
     }
 
     fn get_auction_bump(&self, block_time: u64) -> u64 {
@@ -116,16 +358,27 @@ impl AuctionCalculator {
 
 #[cfg(test)]
 mod test {
-    use alloy::primitives::U256;
+    use alloy::{
+        primitives::{Address, U256},
+        providers::ProviderBuilder,
+        rpc::types::{Block, Transaction},
+        transports::mock::Asserter,
+    };
 
     use crate::{
         auction_calculator::AuctionCalculator,
         fusion::{
             auction_details::{AuctionDetails, AuctionPoint},
+            fusion_extension::FusionExtension,
             fusion_order::IntegratorFee,
             settlement_post_interaction::SettlementPostInteractionData,
         },
+        limit::extension_builder::ExtensionBuildable,
+        multichain_address::MultichainAddress,
         quote::GasCostConfig,
+        test_utils::active_order_fixture,
+        utils::bps::Bps,
+        whitelist::WhitelistItem,
     };
 
     #[test]
@@ -168,46 +421,293 @@ mod test {
         };
 
         let calculator = AuctionCalculator::from_auction_data(data, details);
-        let t1 = calculator.get_auction_bump(calculator.start_time);
-        println!(
-            "t1 = {t1} {}",
-            AuctionCalculator::calc_auction_taking_amount(U256::from(1_000_000), t1)
+        // the curve starts with the auction, not with the resolving start of the whitelist
+        assert_eq!(calculator.start_time, 1754118183);
+        assert_eq!(calculator.finish_time(), 1754118183 + 180);
+        let taking_amount = U256::from(1_000_000);
+
+        let at = |offset: u64| {
+            let bump = calculator.get_auction_bump(calculator.start_time + offset);
+            (
+                bump,
+                AuctionCalculator::calc_auction_taking_amount(taking_amount, bump),
+            )
+        };
+
+        assert_eq!(at(0), (525220, U256::from(1_052_522)));
+        assert_eq!(at(45), (471109, U256::from(1_047_111)));
+        assert_eq!(at(90), (416998, U256::from(1_041_700)));
+        assert_eq!(at(120), (380924, U256::from(1_038_093)));
+        assert_eq!(at(135), (352002, U256::from(1_035_201)));
+        assert_eq!(at(179), (267166, U256::from(1_026_717)));
+        assert_eq!(at(180), (0, U256::from(1_000_000)));
+    }
+
+    #[test]
+    fn test_auction_curve() {
+        let calculator = AuctionCalculator {
+            start_time: 1000,
+            duration: 100,
+            initial_rate_bump: 100_000,
+            points: vec![AuctionPoint {
+                delay: 50,
+                coefficient: 50_000,
+            }],
+            taker_fee_ratio: 0,
+            gas_cost: GasCostConfig {
+                gas_bump_estimate: 10_000,
+                gas_price_estimate: U256::from(1_000),
+            },
+        };
+
+        let curve = calculator.curve(U256::from(1_000_000), 40, None);
+        assert_eq!(
+            curve
+                .samples
+                .iter()
+                .map(|s| (s.timestamp, s.rate_bump))
+                .collect::<Vec<_>>(),
+            vec![(1000, 100_000), (1040, 60_000), (1080, 20_000), (1100, 0)]
+        );
+        assert_eq!(curve.samples[0].taking_amount, U256::from(1_010_000));
+
+        // gas bump = 10_000 * 500_000_000 / 1_000 / 1_000_000 = 5_000
+        let curve = calculator.curve(U256::from(1_000_000), 40, Some(U256::from(500_000_000)));
+        assert_eq!(curve.samples[0].rate_bump, 95_000);
+        assert_eq!(curve.samples[3].rate_bump, 0);
+
+        assert_eq!(
+            curve.to_csv().lines().take(2).collect::<Vec<_>>(),
+            vec!["timestamp,rate_bump,taking_amount", "1000,95000,1009500"]
+        );
+        assert!(
+            curve
+                .to_json()
+                .starts_with(r#"[{"timestamp":1000,"rateBump":95000,"takingAmount":"0xf675c"}"#)
+        );
+    }
+
+    #[test]
+    fn test_auction_curve_with_fee() {
+        let calculator = AuctionCalculator {
+            start_time: 1000,
+            duration: 100,
+            initial_rate_bump: 100_000,
+            points: vec![],
+            taker_fee_ratio: Bps::to_ratio_format(Some(50)),
+            gas_cost: GasCostConfig::default(),
+        };
+        let taking_amount = U256::from(1_000_000);
+
+        let curve = calculator.curve(taking_amount, 30, None);
+        for sample in &curve.samples {
+            assert_eq!(
+                sample.taking_amount,
+                calculator.taking_amount_at(taking_amount, sample.timestamp, U256::ZERO)
+            );
+        }
+        // 1% auction bump plus 0.5% integrator fee on top
+        assert_eq!(curve.samples[0].taking_amount, U256::from(1_015_050));
+
+        // a step past the end of u64 stops at the finish time
+        let curve = calculator.curve(taking_amount, u64::MAX, None);
+        assert_eq!(
+            curve
+                .samples
+                .iter()
+                .map(|s| s.timestamp)
+                .collect::<Vec<_>>(),
+            vec![1000, 1100]
         );
+    }
+
+    #[test]
+    fn test_time_for_taking_amount() {
+        let calculator = AuctionCalculator {
+            start_time: 1754118183,
+            duration: 180,
+            initial_rate_bump: 525220,
+            points: vec![
+                AuctionPoint {
+                    delay: 120,
+                    coefficient: 380924,
+                },
+                AuctionPoint {
+                    delay: 60,
+                    coefficient: 265238,
+                },
+            ],
+            taker_fee_ratio: 0,
+            gas_cost: GasCostConfig {
+                gas_bump_estimate: 265238,
+                gas_price_estimate: U256::from(10),
+            },
+        };
+        let taking_amount = U256::from(1_000_000);
 
-        let tmid = calculator.get_auction_bump(calculator.start_time + calculator.duration / 4);
-        println!(
-            "tmid = {tmid} {}",
-            AuctionCalculator::calc_auction_taking_amount(U256::from(1_000_000), tmid)
+        assert_eq!(
+            calculator.time_for_taking_amount(taking_amount, U256::from(1_041_700), U256::ZERO),
+            Some(calculator.start_time + 90)
+        );
+        assert_eq!(
+            calculator.time_for_taking_amount(taking_amount, U256::from(2_000_000), U256::ZERO),
+            Some(calculator.start_time)
+        );
+        assert_eq!(
+            calculator.time_for_taking_amount(taking_amount, taking_amount, U256::ZERO),
+            Some(calculator.finish_time())
+        );
+        assert_eq!(
+            calculator.time_for_taking_amount(taking_amount, U256::from(999_999), U256::ZERO),
+            None
         );
 
-        let tmid = calculator.get_auction_bump(calculator.start_time + calculator.duration / 2);
-        println!(
-            "tmid = {tmid} {}",
-            AuctionCalculator::calc_auction_taking_amount(U256::from(1_000_000), tmid)
+        // 0.3% integrator fee on top of the 1_000_000 end amount
+        let fee_calculator = AuctionCalculator {
+            taker_fee_ratio: Bps::to_ratio_format(Some(30)),
+            ..calculator.clone()
+        };
+        assert_eq!(
+            fee_calculator.time_for_taking_amount(taking_amount, U256::from(1_002_999), U256::ZERO),
+            None
+        );
+        assert_eq!(
+            fee_calculator.time_for_taking_amount(taking_amount, U256::from(1_003_000), U256::ZERO),
+            Some(calculator.finish_time())
         );
+        assert!(
+            fee_calculator.time_for_taking_amount(taking_amount, U256::from(1_041_700), U256::ZERO)
+                > Some(calculator.start_time + 90)
+        );
+
+        // compare against sampling the curve second by second, with and without integrator fee
+        let with_fee = AuctionCalculator {
+            taker_fee_ratio: Bps::to_ratio_format(Some(30)),
+            ..calculator.clone()
+        };
+        for (calculator, base_fee) in [&calculator, &with_fee].into_iter().flat_map(|calculator| {
+            [0u64, 4_000_000, 9_000_000].map(|base_fee| (calculator, U256::from(base_fee)))
+        }) {
+            let curve = calculator.curve(taking_amount, 1, Some(base_fee));
+
+            for target in (1_000_000..1_060_000).step_by(997) {
+                let target = U256::from(target);
+                let expected = curve
+                    .samples
+                    .iter()
+                    .find(|sample| sample.taking_amount <= target)
+                    .map(|sample| sample.timestamp);
+
+                assert_eq!(
+                    calculator.time_for_taking_amount(taking_amount, target, base_fee),
+                    expected,
+                    "target {target} base fee {base_fee}"
+                );
+            }
+        }
+    }
 
-        let tmid = calculator.get_auction_bump(calculator.start_time + 120);
-        println!(
-            "tmid 120 = {tmid} {}",
-            AuctionCalculator::calc_auction_taking_amount(U256::from(1_000_000), tmid)
+    #[test]
+    fn test_from_active_order() {
+        let order = active_order_fixture();
+
+        let calculator = AuctionCalculator::from_active_order(&order).unwrap();
+        assert_eq!(calculator.start_time, 0x688b2c26);
+        assert_eq!(calculator.duration, 180);
+        assert_eq!(calculator.initial_rate_bump, 696150);
+        assert_eq!(calculator.taker_fee_ratio, 0);
+
+        let taking_amount = order.order.takingAmount;
+        assert_eq!(
+            calculator.taking_amount_at(taking_amount, calculator.start_time, U256::ZERO),
+            U256::from(979_485)
+        );
+        assert_eq!(
+            calculator.taking_amount_at(taking_amount, calculator.finish_time(), U256::ZERO),
+            taking_amount
         );
+    }
 
-        let tmid = calculator.get_auction_bump(calculator.start_time + calculator.duration * 3 / 4);
-        println!(
-            "tmid = {tmid} {}",
-            AuctionCalculator::calc_auction_taking_amount(U256::from(1_000_000), tmid)
+    #[test]
+    fn test_from_extension_with_fee() {
+        let extension = FusionExtension::new(
+            MultichainAddress::from_raw(Address::ZERO.create(0)),
+            AuctionDetails {
+                start_time: 1754118183,
+                duration: 180,
+                initial_rate_bump: 100_000,
+                points: vec![],
+                gas_cost: GasCostConfig::default(),
+            },
+            SettlementPostInteractionData {
+                whitelist: vec![WhitelistItem {
+                    address_half: "0xcb4fa6eb00f6ea887a4a".parse().unwrap(),
+                    delay: 0,
+                }],
+                integrator_fee: Some(IntegratorFee {
+                    receiver: MultichainAddress::from_raw(Address::ZERO.create(1)),
+                    ratio: Bps::to_ratio_format(Some(100)),
+                }),
+                bank_fee: None,
+                resolving_start_time: 1754118166,
+                custom_receiver: None,
+            },
+            None,
+        )
+        .build();
+
+        let calculator = AuctionCalculator::from_extension(&extension).unwrap();
+        assert_eq!(calculator.start_time, 1754118183);
+        assert_eq!(calculator.taker_fee_ratio, 1_000);
+
+        // 1% rate bump, then 1% integrator fee on top
+        assert_eq!(
+            calculator.taking_amount_at(U256::from(1_000_000), calculator.start_time, U256::ZERO),
+            U256::from(1_020_100)
         );
+    }
+
+    #[tokio::test]
+    async fn test_latest_block_taking_amount() {
+        let calculator = AuctionCalculator {
+            start_time: 1000,
+            duration: 100,
+            initial_rate_bump: 100_000,
+            points: vec![],
+            taker_fee_ratio: 0,
+            gas_cost: GasCostConfig {
+                gas_bump_estimate: 10_000,
+                gas_price_estimate: U256::from(1_000),
+            },
+        };
 
-        let tn = calculator.get_auction_bump(calculator.start_time + 179);
-        println!(
-            "tn = {tn} {}",
-            AuctionCalculator::calc_auction_taking_amount(U256::from(1_000_000), tn)
+        let asserter = Asserter::new();
+        let mut block = Block::<Transaction>::default();
+        block.header.inner.timestamp = 1050;
+        block.header.inner.base_fee_per_gas = Some(500_000_000);
+        asserter.push_success(&block);
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter);
+
+        // auction bump 50_000, gas bump 5_000
+        assert_eq!(
+            calculator
+                .latest_block_taking_amount(&provider, U256::from(1_000_000))
+                .await
+                .unwrap(),
+            U256::from(1_004_500)
         );
 
-        let tn = calculator.get_auction_bump(calculator.start_time + calculator.duration);
-        println!(
-            "tn = {tn} {}",
-            AuctionCalculator::calc_auction_taking_amount(U256::from(1_000_000), tn)
+        assert_eq!(
+            calculator.simulate_base_fees(
+                U256::from(1_000_000),
+                1050,
+                &[U256::ZERO, U256::from(500_000_000), U256::MAX]
+            ),
+            vec![
+                (U256::ZERO, U256::from(1_005_000)),
+                (U256::from(500_000_000), U256::from(1_004_500)),
+                (U256::MAX, U256::from(1_000_000)),
+            ]
         );
     }
 }
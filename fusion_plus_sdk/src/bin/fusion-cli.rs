@@ -0,0 +1,596 @@
+use std::{collections::HashSet, path::PathBuf, str::FromStr, time::Duration};
+
+use alloy::{
+    primitives::{B256, Bytes, U256},
+    providers::Provider,
+    signers::local::PrivateKeySigner,
+};
+use chrono::Utc;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use fusion_plus_sdk::{
+    addresses::{get_limit_order_contract_address, get_permit2_contract_address},
+    api::{
+        Api,
+        types::{ActiveOrdersRequestParams, OrdersByMakerParams},
+    },
+    cancellation::cancel_order,
+    chain_id::ChainId,
+    constants::NATIVE_CURRENCY,
+    cross_chain_order::{CrossChainOrderParams, Fee, PreparedOrder},
+    escrow_extension::EscrowExtension,
+    fusion::fusion_extension::FusionExtension,
+    hash_lock::HashLock,
+    limit::{
+        epoch_manager::{get_epoch, increase_epoch},
+        extension::Extension,
+        maker_traits::MakerTraits,
+        permit::{Eip2612Permit, MakerPermit, Permit2Single},
+    },
+    multichain_address::MultichainAddress,
+    native::wrap_native,
+    order_summary::{EscrowExtraDataSummary, MakerTraitsSummary, SaltSummary},
+    quote::{QuoteRequest, preset::PresetType},
+    relayer_request::RelayerRequest,
+    simulation::simulate_order,
+    time_locks::TimeLocks,
+    tokens::{TokenAmount, TokenRegistry},
+    utils::{
+        alloy::{create_provider, ensure_allowance},
+        random::get_random_bytes32,
+    },
+};
+use serde::Serialize;
+
+#[derive(Parser)]
+#[command(
+    name = "fusion-cli",
+    about = "Quote, place, inspect and track 1inch Fusion+ orders"
+)]
+struct Cli {
+    #[arg(
+        long,
+        env = "ONEINCH_API_URL",
+        default_value = "https://api.1inch.dev/fusion-plus"
+    )]
+    api_url: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Get a quote for a cross chain swap
+    Quote {
+        #[command(flatten)]
+        swap: SwapArgs,
+        /// Wallet the quote is requested for
+        #[arg(long, default_value_t = MultichainAddress::ZERO)]
+        maker: MultichainAddress,
+    },
+    /// Quote, sign and submit an order using PRIVATE_KEY
+    Place {
+        #[command(flatten)]
+        swap: SwapArgs,
+        #[command(flatten)]
+        order: PlaceArgs,
+    },
+    /// Cancel a pending order on chain using PRIVATE_KEY and wait for the relayer to confirm
+    Cancel {
+        order_hash: B256,
+        /// Source chain of the order
+        #[arg(long)]
+        chain: ChainId,
+    },
+    /// Cancel every outstanding order of a series built with place --series
+    IncreaseEpoch {
+        #[arg(long)]
+        chain: ChainId,
+        #[arg(long)]
+        series: u64,
+    },
+    /// Show the status of an order
+    Status { order_hash: B256 },
+    /// Show published secrets and fills ready to accept a secret
+    Secrets { order_hash: B256 },
+    /// Decode an order extension, works offline
+    DecodeExtension { extension: Bytes },
+    /// Decode raw order values, works offline
+    Decode {
+        #[command(subcommand)]
+        value: DecodeCommand,
+    },
+    /// List active orders
+    Active {
+        #[arg(long)]
+        src_chain: Option<ChainId>,
+        #[arg(long)]
+        dst_chain: Option<ChainId>,
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// List orders of a maker
+    MakerOrders {
+        maker: MultichainAddress,
+        #[arg(long)]
+        src_chain: Option<ChainId>,
+        #[arg(long)]
+        dst_chain: Option<ChainId>,
+        #[command(flatten)]
+        page: PageArgs,
+    },
+}
+
+#[derive(Subcommand)]
+enum DecodeCommand {
+    /// Flags and fields of packed maker traits
+    MakerTraits { value: U256 },
+    /// Extension hash, random part and track code of an order salt
+    Salt {
+        value: U256,
+        /// Check the salt against this extension
+        #[arg(long)]
+        extension: Option<Bytes>,
+    },
+    /// Packed time locks
+    TimeLocks { value: U256 },
+    /// Escrow extra data, the last 160 bytes of the post interaction
+    ExtraData { data: Bytes },
+    /// Escrow extra data of a whole order extension
+    Extension { data: Bytes },
+}
+
+#[derive(Args)]
+struct SwapArgs {
+    /// Network name or chain id, e.g. arb or 42161
+    #[arg(long)]
+    src_chain: ChainId,
+    #[arg(long)]
+    dst_chain: ChainId,
+    #[arg(long)]
+    src_token: MultichainAddress,
+    #[arg(long)]
+    dst_token: MultichainAddress,
+    /// Amount of src token in whole tokens, e.g. 1.5, the native currency
+    /// (0xeeee...eeee) is wrapped before placing the order
+    #[arg(
+        long,
+        required_unless_present = "raw_amount",
+        conflicts_with = "raw_amount"
+    )]
+    amount: Option<String>,
+    /// Amount of src token in its smallest unit
+    #[arg(long)]
+    raw_amount: Option<U256>,
+    /// Token list JSON (tokenlists.org format) with decimals of tokens the SDK does not know
+    #[arg(long)]
+    token_list: Option<PathBuf>,
+    /// Integrator fee in bps, charged on top of the auction amount
+    #[arg(long, requires = "fee_receiver")]
+    fee_bps: Option<u16>,
+    /// Receiver of the integrator fee
+    #[arg(long)]
+    fee_receiver: Option<MultichainAddress>,
+}
+
+#[derive(Args)]
+struct PlaceArgs {
+    /// fast, medium, slow or custom, defaults to the recommended preset
+    #[arg(long)]
+    preset: Option<PresetType>,
+    /// Receiver on the destination chain, defaults to the maker
+    #[arg(long)]
+    receiver: Option<MultichainAddress>,
+    /// Build the order in epoch-managed mode for this series, see increase-epoch
+    #[arg(long)]
+    series: Option<u64>,
+    /// How the limit order protocol gets access to the src token
+    #[arg(long, value_enum, default_value_t = Approval::Approve)]
+    approval: Approval,
+    /// Simulate a fill by the first whitelisted resolver against the RPC of the src chain
+    /// before submitting, point the RPC at an anvil fork to keep it local
+    #[arg(long)]
+    simulate: bool,
+    /// Keep running and submit secrets once the escrows are deployed
+    #[arg(long)]
+    wait: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Approval {
+    /// On-chain approve of the limit order protocol, skipped if the allowance is enough
+    Approve,
+    /// Signed EIP-2612 permit, the token must support it
+    Permit,
+    /// Signed Permit2 allowance, approves Permit2 on chain once if needed
+    Permit2,
+}
+
+#[derive(Args)]
+struct PageArgs {
+    #[arg(long)]
+    page: Option<usize>,
+    #[arg(long)]
+    limit: Option<usize>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PlacedOrder {
+    order_hash: B256,
+    quote_id: String,
+    secrets: Vec<B256>,
+    secret_hashes: Vec<B256>,
+}
+
+#[tokio::main]
+async fn main() -> fusion_plus_sdk::Result<()> {
+    dotenvy::dotenv().ok();
+
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Quote { swap, maker } => {
+            let quote = api(&cli.api_url)?
+                .get_quote(&swap.quote_request(maker)?)
+                .await?;
+            print_json(&quote);
+        }
+        Command::Place { swap, order } => place(&cli.api_url, swap, order).await?,
+        Command::Cancel { order_hash, chain } => {
+            let wallet = wallet()?;
+            let cancellation = cancel_order(
+                &api(&cli.api_url)?,
+                &create_provider(chain, wallet)?,
+                chain,
+                order_hash,
+                Duration::from_secs(5),
+                24,
+            )
+            .await?;
+            print_json(&cancellation);
+        }
+        Command::IncreaseEpoch { chain, series } => {
+            let tx_hash =
+                increase_epoch(&create_provider(chain, wallet()?)?, chain, series).await?;
+            println!("{tx_hash}");
+        }
+        Command::Status { order_hash } => {
+            print_json(&api(&cli.api_url)?.get_order_status(order_hash).await?);
+        }
+        Command::Secrets { order_hash } => {
+            let api = api(&cli.api_url)?;
+            print_json(&api.get_published_secrets(&order_hash).await?);
+            print_json(&api.get_ready_to_accept_secret_fills(&order_hash).await?);
+        }
+        Command::DecodeExtension { extension } => decode_extension(extension)?,
+        Command::Decode { value } => decode(value)?,
+        Command::Active {
+            src_chain,
+            dst_chain,
+            page,
+        } => {
+            let orders = api(&cli.api_url)?
+                .get_active_orders(
+                    ActiveOrdersRequestParams {
+                        src_chain_id: src_chain,
+                        dst_chain_id: dst_chain,
+                    }
+                    .with_pagination(page.page, page.limit),
+                )
+                .await?;
+            print_json(&orders);
+        }
+        Command::MakerOrders {
+            maker,
+            src_chain,
+            dst_chain,
+            page,
+        } => {
+            let orders = api(&cli.api_url)?
+                .get_orders_by_maker(
+                    maker,
+                    OrdersByMakerParams {
+                        src_chain,
+                        dst_chain,
+                        ..Default::default()
+                    }
+                    .with_pagination(page.page, page.limit),
+                )
+                .await?;
+            print_json(&orders);
+        }
+    }
+
+    Ok(())
+}
+
+impl SwapArgs {
+    fn quote_request(
+        &self,
+        maker: impl Into<MultichainAddress>,
+    ) -> fusion_plus_sdk::Result<QuoteRequest> {
+        let amount = match (&self.amount, self.raw_amount) {
+            (_, Some(raw_amount)) => raw_amount,
+            (Some(amount), None) => {
+                let mut registry = TokenRegistry::with_defaults();
+                if let Some(path) = &self.token_list {
+                    let json = std::fs::read_to_string(path)
+                        .map_err(|e| fusion_plus_sdk::Error::InternalError(e.to_string()))?;
+                    registry.extend_from_token_list_json(&json)?;
+                }
+
+                let token = registry
+                    .get(self.src_chain, self.src_token)
+                    .ok_or_else(|| {
+                        fusion_plus_sdk::Error::TokenNotFound(format!(
+                            "{} on {}, pass --token-list or --raw-amount",
+                            self.src_token, self.src_chain
+                        ))
+                    })?
+                    .clone();
+                let amount = TokenAmount::from_decimal(token, amount)?;
+                eprintln!("Amount: {amount}");
+                amount.amount
+            }
+            (None, None) => unreachable!("clap requires --amount or --raw-amount"),
+        };
+
+        Ok(QuoteRequest::new(
+            self.src_chain,
+            self.dst_chain,
+            self.src_token,
+            self.dst_token,
+            amount,
+            true,
+            maker,
+        )
+        .with_fee(self.fee_bps.unwrap_or_default()))
+    }
+
+    fn fee(&self) -> Option<Fee> {
+        Some(Fee {
+            taking_fee_bps: self.fee_bps?,
+            taking_fee_receiver: self.fee_receiver?,
+        })
+    }
+}
+
+fn api(base_url: &str) -> fusion_plus_sdk::Result<Api> {
+    let api_key = std::env::var("ONEINCH_API_KEY")
+        .map_err(|_| fusion_plus_sdk::Error::InternalErrorStr("ONEINCH_API_KEY not set"))?;
+    Ok(Api::new(base_url, api_key))
+}
+
+fn wallet() -> fusion_plus_sdk::Result<PrivateKeySigner> {
+    PrivateKeySigner::from_str(
+        &std::env::var("PRIVATE_KEY")
+            .map_err(|_| fusion_plus_sdk::Error::InternalErrorStr("PRIVATE_KEY not set"))?,
+    )
+    .map_err(|e| fusion_plus_sdk::Error::InternalError(e.to_string()))
+}
+
+fn print_json(value: &impl Serialize) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(value).expect("API types are serializable")
+    );
+}
+
+async fn maker_permit(
+    provider: &impl Provider,
+    wallet: &PrivateKeySigner,
+    quote_request: &QuoteRequest,
+    approval: Approval,
+) -> fusion_plus_sdk::Result<Option<MakerPermit>> {
+    let chain_id = quote_request.src_chain_id;
+    let token = quote_request.src_token_address.as_raw();
+    let amount = quote_request.src_amount;
+    // long enough for the auction to start and run, the permit is used on the first fill
+    let deadline = Utc::now().timestamp() as u64 + 3600;
+
+    match approval {
+        Approval::Approve => {
+            ensure_allowance(
+                provider,
+                token,
+                wallet.address(),
+                get_limit_order_contract_address(chain_id).as_raw(),
+                amount,
+            )
+            .await?;
+            Ok(None)
+        }
+        Approval::Permit => {
+            let permit = Eip2612Permit::fetch(
+                provider,
+                chain_id,
+                token,
+                wallet.address(),
+                amount,
+                deadline,
+            )
+            .await?;
+            Ok(Some(permit.sign(wallet).await?))
+        }
+        Approval::Permit2 => {
+            ensure_allowance(
+                provider,
+                token,
+                wallet.address(),
+                get_permit2_contract_address(chain_id).as_raw(),
+                amount,
+            )
+            .await?;
+            let permit = Permit2Single::fetch(
+                provider,
+                chain_id,
+                token,
+                wallet.address(),
+                amount,
+                deadline,
+            )
+            .await?;
+            Ok(Some(permit.sign(wallet).await?))
+        }
+    }
+}
+
+async fn place(api_url: &str, swap: SwapArgs, args: PlaceArgs) -> fusion_plus_sdk::Result<()> {
+    let PlaceArgs {
+        preset,
+        receiver,
+        series,
+        approval,
+        simulate,
+        wait,
+    } = args;
+
+    let api = api(api_url)?;
+    let wallet = wallet()?;
+
+    let quote_request = swap
+        .quote_request(wallet.address())?
+        .with_wrapped_native_src();
+    let quote_result = api.get_quote(&quote_request).await?;
+
+    let provider = create_provider(quote_request.src_chain_id, wallet.clone())?;
+    if swap.src_token == NATIVE_CURRENCY {
+        eprintln!("Wrapping native currency");
+        wrap_native(
+            &provider,
+            quote_request.src_chain_id,
+            wallet.address(),
+            quote_request.src_amount,
+        )
+        .await?;
+    }
+    let permit = maker_permit(&provider, &wallet, &quote_request, approval).await?;
+
+    let epoch = match series {
+        Some(series) => Some(
+            get_epoch(
+                &provider,
+                quote_request.src_chain_id,
+                wallet.address(),
+                series,
+            )
+            .await?,
+        ),
+        None => None,
+    };
+
+    let secrets_count = preset
+        .and_then(|preset| quote_result.get_preset(preset))
+        .unwrap_or_else(|| quote_result.recommended_preset())
+        .secrets_count;
+    let secrets: Vec<B256> = (0..secrets_count).map(|_| get_random_bytes32()).collect();
+    let secret_hashes: Vec<B256> = secrets.iter().map(HashLock::hash_secret).collect();
+
+    let order = PreparedOrder::from_quote(
+        &quote_request,
+        &quote_result,
+        CrossChainOrderParams {
+            dst_address: receiver.unwrap_or(MultichainAddress::from_raw(wallet.address())),
+            hash_lock: HashLock::from_secrets(&secrets)?,
+            secret_hashes: secret_hashes.clone(),
+            fee: swap.fee(),
+            resolver_fee: None,
+            preset,
+            epoch,
+            permit,
+        },
+    )?;
+
+    let order_hash = order.eip712_signing_hash()?;
+    let signature = order.sign(&wallet).await?;
+
+    if simulate {
+        let resolver =
+            quote_result
+                .whitelist
+                .first()
+                .ok_or(fusion_plus_sdk::Error::InternalErrorStr(
+                    "quote has no whitelisted resolver",
+                ))?;
+        let simulation = simulate_order(&provider, &order, &signature, resolver.as_raw()).await?;
+        if !simulation.is_valid() {
+            return Err(fusion_plus_sdk::Error::InternalError(format!(
+                "simulation failed: {:?}, revert data {:?}",
+                simulation.status, simulation.revert_data
+            )));
+        }
+        eprintln!("Simulation passed");
+    }
+
+    let relayer_request = RelayerRequest::try_from_prepared_order(&order, &signature)?;
+    api.submit_order(relayer_request).await?;
+
+    // secrets are only printed here, they are needed to complete the swap
+    print_json(&PlacedOrder {
+        order_hash,
+        quote_id: order.quote_id.clone(),
+        secrets: secrets.clone(),
+        secret_hashes,
+    });
+
+    if wait {
+        let mut submitted = HashSet::new();
+        while submitted.len() < secrets.len() {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+
+            let ready = api.get_ready_to_accept_secret_fills(&order_hash).await?;
+            for fill in ready.fills {
+                let idx = fill.idx as usize;
+                if idx < secrets.len() && submitted.insert(idx) {
+                    api.submit_secret(&order_hash, &secrets[idx]).await?;
+                    eprintln!("Submitted secret {idx}");
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn decode_extension(bytes: Bytes) -> fusion_plus_sdk::Result<()> {
+    let extension = Extension::decode_from(bytes)?;
+
+    if let Ok(escrow_extension) = EscrowExtension::from_extension(extension.clone()) {
+        println!("{escrow_extension:#?}");
+    } else if let Ok(fusion_extension) = FusionExtension::from_extension(extension.clone()) {
+        println!("{fusion_extension:#?}");
+    } else {
+        println!("{extension:#?}");
+    }
+
+    Ok(())
+}
+
+fn decode(command: DecodeCommand) -> fusion_plus_sdk::Result<()> {
+    match command {
+        DecodeCommand::MakerTraits { value } => {
+            println!("{}", MakerTraitsSummary::from(&MakerTraits::new(value)));
+        }
+        DecodeCommand::Salt { value, extension } => {
+            let salt = SaltSummary::new(value);
+            println!("{salt}");
+            if let Some(extension) = extension {
+                let extension = Extension::decode_from(extension)?;
+                println!(
+                    "  matches extension:  {}",
+                    salt.matches_extension(&extension)
+                );
+            }
+        }
+        DecodeCommand::TimeLocks { value } => println!("{}", TimeLocks::from_u256(value)),
+        DecodeCommand::ExtraData { data } => {
+            println!("{}", EscrowExtraDataSummary::decode_from(data)?);
+        }
+        DecodeCommand::Extension { data } => {
+            println!("{}", EscrowExtraDataSummary::from_extension(data)?);
+        }
+    }
+
+    Ok(())
+}
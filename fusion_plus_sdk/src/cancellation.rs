@@ -0,0 +1,316 @@
+use std::time::Duration;
+
+use alloy::{
+    network::TransactionBuilder,
+    primitives::{Address, B256, U256},
+    providers::{Provider, WalletProvider},
+    rpc::types::TransactionRequest,
+    sol,
+    sol_types::SolCall,
+};
+use serde::Serialize;
+
+use crate::{
+    addresses::get_limit_order_contract_address,
+    api::{
+        Api,
+        types::{OrderStatus, OrderStatusResponse},
+    },
+    chain_id::ChainId,
+    limit::{eip712::LimitOrderV4, maker_traits::MakerTraits},
+};
+
+sol! {
+    #[sol(rpc)]
+    interface ILimitOrderProtocol {
+        function cancelOrder(uint256 makerTraits, bytes32 orderHash) external;
+        function bitsInvalidateForOrder(uint256 makerTraits, uint256 additionalMask) external;
+        function bitInvalidatorForOrder(address maker, uint256 slot) external view returns (uint256);
+        function remainingInvalidatorForOrder(address maker, bytes32 orderHash) external view returns (uint256);
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cancellation {
+    pub tx_hash: B256,
+    pub status: OrderStatus,
+    pub cancel_tx: Option<String>,
+}
+
+// In bit invalidator mode the nonce selects a 256 bit slot and a bit inside of it
+fn bit_invalidator_position(maker_traits: &MakerTraits) -> (U256, U256) {
+    let nonce = maker_traits.nonce_or_epoch();
+    (U256::from(nonce >> 8), U256::ONE << (nonce & 0xff))
+}
+
+pub fn cancel_order_tx(
+    chain_id: ChainId,
+    maker_traits: &MakerTraits,
+    order_hash: B256,
+) -> TransactionRequest {
+    let call = ILimitOrderProtocol::cancelOrderCall {
+        makerTraits: maker_traits.as_u256(),
+        orderHash: order_hash,
+    };
+
+    TransactionRequest::default()
+        .with_to(get_limit_order_contract_address(chain_id).as_raw())
+        .with_input(call.abi_encode())
+}
+
+// Invalidates every order of the maker sharing the nonce slot with this one,
+// `additional_mask` selects further nonces of the slot to cancel
+pub fn bits_invalidate_for_order_tx(
+    chain_id: ChainId,
+    maker_traits: &MakerTraits,
+    additional_mask: U256,
+) -> crate::Result<TransactionRequest> {
+    if !maker_traits.is_bit_invalidator_mode() {
+        return Err(crate::Error::InternalErrorStr(
+            "bitsInvalidateForOrder requires bit invalidator mode, use cancelOrder",
+        ));
+    }
+
+    let call = ILimitOrderProtocol::bitsInvalidateForOrderCall {
+        makerTraits: maker_traits.as_u256(),
+        additionalMask: additional_mask,
+    };
+
+    Ok(TransactionRequest::default()
+        .with_to(get_limit_order_contract_address(chain_id).as_raw())
+        .with_input(call.abi_encode()))
+}
+
+pub async fn is_order_invalidated(
+    provider: &impl Provider,
+    chain_id: ChainId,
+    order: &LimitOrderV4,
+) -> crate::Result<bool> {
+    let protocol = ILimitOrderProtocol::new(
+        get_limit_order_contract_address(chain_id).as_raw(),
+        provider,
+    );
+    let maker_traits = MakerTraits::new(order.makerTraits);
+
+    if maker_traits.is_bit_invalidator_mode() {
+        let (slot, bit) = bit_invalidator_position(&maker_traits);
+        let invalidator = protocol
+            .bitInvalidatorForOrder(order.maker, slot)
+            .call()
+            .await
+            .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+
+        Ok(!(invalidator & bit).is_zero())
+    } else {
+        // cancelled and fully filled orders both store type(uint256).max
+        let invalidator = protocol
+            .remainingInvalidatorForOrder(order.maker, order.get_order_hash(chain_id)?)
+            .call()
+            .await
+            .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+
+        Ok(invalidator == U256::MAX)
+    }
+}
+
+// Only the maker can cancel, the protocol would accept the transaction from anyone else
+// but invalidate the sender's own order hash instead
+fn check_cancellable(
+    status: &OrderStatusResponse,
+    chain_id: ChainId,
+    order_hash: B256,
+    sender: Address,
+) -> crate::Result<()> {
+    if status.status != OrderStatus::Pending {
+        return Err(crate::Error::InternalError(format!(
+            "order {order_hash} is {:?}, only pending orders can be cancelled",
+            status.status
+        )));
+    }
+    if status.order.get_order_hash(chain_id)? != order_hash {
+        return Err(crate::Error::InternalError(format!(
+            "order {order_hash} was not created on {chain_id}"
+        )));
+    }
+    if status.order.maker != sender {
+        return Err(crate::Error::InternalError(format!(
+            "order {order_hash} was made by {}, it can not be cancelled by {sender}",
+            status.order.maker
+        )));
+    }
+
+    Ok(())
+}
+
+// Cancels a pending order on chain with the maker wallet of `provider`,
+// then waits until the relayer reports it as cancelled
+pub async fn cancel_order(
+    api: &Api,
+    provider: &(impl Provider + WalletProvider),
+    chain_id: ChainId,
+    order_hash: B256,
+    poll_interval: Duration,
+    max_attempts: usize,
+) -> crate::Result<Cancellation> {
+    let status = api.get_order_status(order_hash).await?;
+    check_cancellable(
+        &status,
+        chain_id,
+        order_hash,
+        provider.default_signer_address(),
+    )?;
+
+    let tx = cancel_order_tx(
+        chain_id,
+        &MakerTraits::new(status.order.makerTraits),
+        order_hash,
+    );
+    let receipt = provider
+        .send_transaction(tx)
+        .await?
+        .get_receipt()
+        .await
+        .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+    if !receipt.status() {
+        return Err(crate::Error::InternalError(format!(
+            "cancel transaction {} reverted",
+            receipt.transaction_hash
+        )));
+    }
+
+    for _ in 0..max_attempts {
+        let status = api.get_order_status(order_hash).await?;
+        if status.status == OrderStatus::Cancelled {
+            return Ok(Cancellation {
+                tx_hash: receipt.transaction_hash,
+                status: status.status,
+                cancel_tx: status.cancel_tx,
+            });
+        }
+
+        tokio::time::sleep(poll_interval).await;
+    }
+
+    Err(crate::Error::InternalError(format!(
+        "cancel transaction {} mined, but the relayer still reports order {order_hash} as not cancelled",
+        receipt.transaction_hash
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        primitives::{Bytes, address, b256},
+        providers::ProviderBuilder,
+        transports::mock::Asserter,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_cancel_order_tx() {
+        let order_hash =
+            b256!("0x5e30e2d3d2e8a1ebf7e1c1e5b1e7b8d9c0a4f2e3b1d7c9a8e6f5d4c3b2a19080");
+        let maker_traits = MakerTraits::default().try_with_nonce(300).unwrap();
+
+        let tx = cancel_order_tx(ChainId::Arbitrum, &maker_traits, order_hash);
+        assert_eq!(
+            tx.to.unwrap().to(),
+            Some(&address!("0x111111125421ca6dc452d289314280a0f8842a65"))
+        );
+
+        let input = tx.input.input().unwrap();
+        assert_eq!(input[..4], ILimitOrderProtocol::cancelOrderCall::SELECTOR);
+        let call = ILimitOrderProtocol::cancelOrderCall::abi_decode(input).unwrap();
+        assert_eq!(call.makerTraits, maker_traits.as_u256());
+        assert_eq!(call.orderHash, order_hash);
+    }
+
+    #[test]
+    fn test_bits_invalidate_for_order_tx() {
+        let maker_traits = MakerTraits::default().allow_multiple_fills();
+        assert!(
+            bits_invalidate_for_order_tx(ChainId::Arbitrum, &maker_traits, U256::ZERO).is_err()
+        );
+
+        let maker_traits = maker_traits
+            .disable_multiple_fills()
+            .try_with_nonce(300)
+            .unwrap();
+        let tx =
+            bits_invalidate_for_order_tx(ChainId::Arbitrum, &maker_traits, U256::from(3)).unwrap();
+        let call =
+            ILimitOrderProtocol::bitsInvalidateForOrderCall::abi_decode(tx.input.input().unwrap())
+                .unwrap();
+        assert_eq!(call.makerTraits, maker_traits.as_u256());
+        assert_eq!(call.additionalMask, U256::from(3));
+
+        assert_eq!(
+            bit_invalidator_position(&maker_traits),
+            (U256::ONE, U256::ONE << 44)
+        );
+    }
+
+    #[test]
+    fn test_check_cancellable() {
+        let maker = address!("0x00000000000000000000000000000000deadbeef");
+        let order = LimitOrderV4 {
+            maker,
+            ..Default::default()
+        };
+        let order_hash = order.get_order_hash(ChainId::Arbitrum).unwrap();
+        let mut status = OrderStatusResponse {
+            status: OrderStatus::Pending,
+            order,
+            extension: String::new(),
+            points: None,
+            cancel_tx: None,
+            fills: vec![],
+            created_at: 0,
+            auction_start_date: 0,
+            auction_duration: 0,
+            initial_rate_bump: 0,
+            is_native_currency: false,
+            from_token_to_usd_price: None,
+            to_token_to_usd_price: None,
+        };
+
+        assert!(check_cancellable(&status, ChainId::Arbitrum, order_hash, maker).is_ok());
+        assert!(check_cancellable(&status, ChainId::Arbitrum, order_hash, Address::ZERO).is_err());
+        assert!(check_cancellable(&status, ChainId::Base, order_hash, maker).is_err());
+
+        status.status = OrderStatus::Executed;
+        assert!(check_cancellable(&status, ChainId::Arbitrum, order_hash, maker).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_is_order_invalidated() {
+        let order = LimitOrderV4 {
+            maker: address!("0x00000000000000000000000000000000deadbeef"),
+            makerTraits: MakerTraits::default()
+                .try_with_nonce(300)
+                .unwrap()
+                .as_u256(),
+            ..Default::default()
+        };
+
+        let asserter = Asserter::new();
+        asserter.push_success(&Bytes::from(
+            U256::from((1u64 << 44) | 1).to_be_bytes::<32>(),
+        ));
+        asserter.push_success(&Bytes::from(U256::ONE.to_be_bytes::<32>()));
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter);
+
+        assert!(
+            is_order_invalidated(&provider, ChainId::Arbitrum, &order)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !is_order_invalidated(&provider, ChainId::Arbitrum, &order)
+                .await
+                .unwrap()
+        );
+    }
+}
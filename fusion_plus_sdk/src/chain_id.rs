@@ -6,12 +6,25 @@ use serde::{
     de::{Error, Unexpected},
 };
 
+use crate::chain_metadata::CHAINS;
+
 #[repr(u32)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, TryFromPrimitive)]
 pub enum ChainId {
     Ethereum = 1,
     Optimism = 10,
+    Bnb = 56,
+    Gnosis = 100,
+    Unichain = 130,
+    Polygon = 137,
+    Sonic = 146,
+    ZkSync = 324,
+    // chain id the 1inch API uses for Solana
+    Solana = 501,
+    Base = 8453,
     Arbitrum = 42161,
+    Avalanche = 43114,
+    Linea = 59144,
     Tron = 728126428,
 }
 
@@ -22,28 +35,20 @@ impl Display for ChainId {
 }
 
 impl ChainId {
-    pub fn from_u32(n: u32) -> Self {
-        ChainId::try_from_primitive(n).unwrap()
+    pub fn from_u32(n: u32) -> crate::Result<Self> {
+        ChainId::try_from_primitive(n).map_err(|_| crate::Error::UnsupportedChainId(n))
     }
 
     pub fn from_network_name(name: &str) -> crate::Result<Self> {
-        match name {
-            "ethereum" | "eth" => Ok(ChainId::Ethereum),
-            "optimism" | "op" => Ok(ChainId::Optimism),
-            "arbitrum" | "arb" => Ok(ChainId::Arbitrum),
-            "tron" => Ok(ChainId::Tron),
-            _ => Err(crate::Error::NetworkNameNotRecognised(name.to_string())),
-        }
+        CHAINS
+            .iter()
+            .find(|metadata| metadata.network_names.contains(&name))
+            .map(|metadata| metadata.chain_id)
+            .ok_or_else(|| crate::Error::NetworkNameNotRecognised(name.to_string()))
     }
 
     pub fn to_network_name(&self) -> String {
-        match self {
-            ChainId::Ethereum => "eth",
-            ChainId::Optimism => "op",
-            ChainId::Arbitrum => "arb",
-            ChainId::Tron => "tron",
-        }
-        .to_string()
+        self.metadata().network_names[0].to_string()
     }
 }
 
@@ -87,3 +92,25 @@ impl<'de> Deserialize<'de> for ChainId {
             .map_err(|e| D::Error::invalid_value(Unexpected::Str(&e.to_string()), &"valid ChainId"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_u32() {
+        assert_eq!(ChainId::from_u32(8453).unwrap(), ChainId::Base);
+        assert!(matches!(
+            ChainId::from_u32(5),
+            Err(crate::Error::UnsupportedChainId(5))
+        ));
+    }
+
+    #[test]
+    fn test_network_names() {
+        assert_eq!(ChainId::from_str("bsc").unwrap(), ChainId::Bnb);
+        assert_eq!(ChainId::from_str("43114").unwrap(), ChainId::Avalanche);
+        assert_eq!(ChainId::ZkSync.to_string(), "zksync");
+        assert!(ChainId::from_str("goerli").is_err());
+    }
+}
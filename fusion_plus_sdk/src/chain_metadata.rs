@@ -0,0 +1,337 @@
+use crate::chain_id::ChainId;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChainKind {
+    Evm,
+    // EVM compatible contracts, base58 addresses and its own transaction format
+    Tron,
+    Solana,
+}
+
+// Static description of a network, `None` addresses have no known deployment
+#[derive(Clone, Copy, Debug)]
+pub struct ChainMetadata {
+    pub chain_id: ChainId,
+    pub kind: ChainKind,
+    // short name used in `address@network`, followed by accepted aliases
+    pub network_names: &'static [&'static str],
+    pub native_symbol: &'static str,
+    pub wrapped_native: Option<&'static str>,
+    pub block_time_ms: u64,
+    pub rpc_env_var: &'static str,
+    pub explorer_url: &'static str,
+    pub limit_order_protocol: Option<&'static str>,
+    pub true_erc20: Option<&'static str>,
+    pub permit2: Option<&'static str>,
+    pub usdc: Option<&'static str>,
+    pub usdt: Option<&'static str>,
+}
+
+const LIMIT_ORDER_PROTOCOL: &str = "0x111111125421ca6dc452d289314280a0f8842a65";
+const TRUE_ERC20: &str = "0xda0000d4000015a526378bb6fafc650cea5966f8";
+// Uniswap Permit2, deployed at the same address on most EVM chains
+const PERMIT2: &str = "0x000000000022D473030F116dDEE9F6B43aC78BA3";
+
+pub const CHAINS: [ChainMetadata; 14] = [
+    ChainMetadata {
+        chain_id: ChainId::Ethereum,
+        kind: ChainKind::Evm,
+        network_names: &["eth", "ethereum"],
+        native_symbol: "ETH",
+        wrapped_native: Some("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"),
+        block_time_ms: 12_000,
+        rpc_env_var: "ETH_RPC_URL",
+        explorer_url: "https://etherscan.io",
+        limit_order_protocol: Some(LIMIT_ORDER_PROTOCOL),
+        true_erc20: Some(TRUE_ERC20),
+        permit2: Some(PERMIT2),
+        usdc: Some("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"),
+        usdt: Some("0xdAC17F958D2ee523a2206206994597C13D831ec7"),
+    },
+    ChainMetadata {
+        chain_id: ChainId::Optimism,
+        kind: ChainKind::Evm,
+        network_names: &["op", "optimism"],
+        native_symbol: "ETH",
+        wrapped_native: Some("0x4200000000000000000000000000000000000006"),
+        block_time_ms: 2_000,
+        rpc_env_var: "OPTIMISM_RPC_URL",
+        explorer_url: "https://optimistic.etherscan.io",
+        limit_order_protocol: Some(LIMIT_ORDER_PROTOCOL),
+        true_erc20: Some(TRUE_ERC20),
+        permit2: Some(PERMIT2),
+        usdc: Some("0x0b2C639c533813f4Aa9D7837CAf62653d097Ff85"),
+        usdt: Some("0x94b008aA00579c1307B0EF2c499aD98a8ce58e58"),
+    },
+    ChainMetadata {
+        chain_id: ChainId::Arbitrum,
+        kind: ChainKind::Evm,
+        network_names: &["arb", "arbitrum"],
+        native_symbol: "ETH",
+        wrapped_native: Some("0x82aF49447D8a07e3bd95BD0d56f35241523fBab1"),
+        block_time_ms: 250,
+        rpc_env_var: "ARBITRUM_RPC_URL",
+        explorer_url: "https://arbiscan.io",
+        limit_order_protocol: Some(LIMIT_ORDER_PROTOCOL),
+        true_erc20: Some(TRUE_ERC20),
+        permit2: Some(PERMIT2),
+        usdc: Some("0xaf88d065e77c8cC2239327C5EDb3A432268e5831"),
+        usdt: Some("0xFd086bC7CD5C481DCC9C85ebE478A1C0b69FCbb9"),
+    },
+    ChainMetadata {
+        chain_id: ChainId::Polygon,
+        kind: ChainKind::Evm,
+        network_names: &["polygon", "matic", "pol"],
+        native_symbol: "POL",
+        wrapped_native: Some("0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270"),
+        block_time_ms: 2_000,
+        rpc_env_var: "POLYGON_RPC_URL",
+        explorer_url: "https://polygonscan.com",
+        limit_order_protocol: Some(LIMIT_ORDER_PROTOCOL),
+        true_erc20: Some(TRUE_ERC20),
+        permit2: Some(PERMIT2),
+        usdc: Some("0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359"),
+        usdt: Some("0xc2132D05D31c914a87C6611C10748AEb04B58e8F"),
+    },
+    ChainMetadata {
+        chain_id: ChainId::Bnb,
+        kind: ChainKind::Evm,
+        network_names: &["bnb", "bsc", "binance"],
+        native_symbol: "BNB",
+        wrapped_native: Some("0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c"),
+        block_time_ms: 750,
+        rpc_env_var: "BNB_RPC_URL",
+        explorer_url: "https://bscscan.com",
+        limit_order_protocol: Some(LIMIT_ORDER_PROTOCOL),
+        true_erc20: Some(TRUE_ERC20),
+        permit2: Some(PERMIT2),
+        usdc: Some("0x8AC76a51cc950d9822D68b83fE1Ad97B32Cd580d"),
+        usdt: Some("0x55d398326f99059fF775485246999027B3197955"),
+    },
+    ChainMetadata {
+        chain_id: ChainId::Base,
+        kind: ChainKind::Evm,
+        network_names: &["base"],
+        native_symbol: "ETH",
+        wrapped_native: Some("0x4200000000000000000000000000000000000006"),
+        block_time_ms: 2_000,
+        rpc_env_var: "BASE_RPC_URL",
+        explorer_url: "https://basescan.org",
+        limit_order_protocol: Some(LIMIT_ORDER_PROTOCOL),
+        true_erc20: Some(TRUE_ERC20),
+        permit2: Some(PERMIT2),
+        usdc: Some("0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913"),
+        usdt: None,
+    },
+    ChainMetadata {
+        chain_id: ChainId::Avalanche,
+        kind: ChainKind::Evm,
+        network_names: &["avax", "avalanche"],
+        native_symbol: "AVAX",
+        wrapped_native: Some("0xB31f66AA3C1e785363F0875A1B74E27b85FD66c7"),
+        block_time_ms: 2_000,
+        rpc_env_var: "AVALANCHE_RPC_URL",
+        explorer_url: "https://snowtrace.io",
+        limit_order_protocol: Some(LIMIT_ORDER_PROTOCOL),
+        true_erc20: Some(TRUE_ERC20),
+        permit2: Some(PERMIT2),
+        usdc: Some("0xB97EF9Ef8734C71904D8002F8b6Bc66Dd9c48a6E"),
+        usdt: Some("0x9702230A8Ea53601f5cD2dc00fDBc13d4dF4A8c7"),
+    },
+    ChainMetadata {
+        chain_id: ChainId::Gnosis,
+        kind: ChainKind::Evm,
+        network_names: &["gnosis", "xdai"],
+        native_symbol: "XDAI",
+        wrapped_native: Some("0xe91D153E0b41518A2Ce8Dd3D7944Fa863463a97d"),
+        block_time_ms: 5_000,
+        rpc_env_var: "GNOSIS_RPC_URL",
+        explorer_url: "https://gnosisscan.io",
+        limit_order_protocol: Some(LIMIT_ORDER_PROTOCOL),
+        true_erc20: Some(TRUE_ERC20),
+        permit2: Some(PERMIT2),
+        usdc: Some("0xDDAfbb505ad214D7b80b1f830fcCc89B60fb7A83"),
+        usdt: Some("0x4ECaBa5870353805a9F068101A40E0f32ed605C6"),
+    },
+    ChainMetadata {
+        chain_id: ChainId::ZkSync,
+        kind: ChainKind::Evm,
+        network_names: &["zksync", "era"],
+        native_symbol: "ETH",
+        wrapped_native: Some("0x5AEa5775959fBC2557Cc8789bC1bf90A239D9a91"),
+        block_time_ms: 1_000,
+        rpc_env_var: "ZKSYNC_RPC_URL",
+        explorer_url: "https://era.zksync.network",
+        // zkSync derives contract addresses differently, nothing shares the usual address
+        limit_order_protocol: Some("0x6fd4383cb451173d5f9304f041c7bcbf27d561ff"),
+        true_erc20: None,
+        permit2: None,
+        usdc: Some("0x1d17CBcF0D6D143135aE902365D2E5e2A16538D4"),
+        usdt: Some("0x493257fD37EDB34451f62EDf8D2a0C418852bA4C"),
+    },
+    ChainMetadata {
+        chain_id: ChainId::Linea,
+        kind: ChainKind::Evm,
+        network_names: &["linea"],
+        native_symbol: "ETH",
+        wrapped_native: Some("0xe5D7C2a44FfDDf6b295A15c148167daaAf5Cf34f"),
+        block_time_ms: 2_000,
+        rpc_env_var: "LINEA_RPC_URL",
+        explorer_url: "https://lineascan.build",
+        limit_order_protocol: Some(LIMIT_ORDER_PROTOCOL),
+        true_erc20: Some(TRUE_ERC20),
+        permit2: Some(PERMIT2),
+        usdc: Some("0x176211869cA2b568f2A7D4EE941E073a821EE1ff"),
+        usdt: Some("0xA219439258ca9da29E9Cc4cE5596924745e12B93"),
+    },
+    ChainMetadata {
+        chain_id: ChainId::Sonic,
+        kind: ChainKind::Evm,
+        network_names: &["sonic"],
+        native_symbol: "S",
+        wrapped_native: Some("0x039e2fB66102314Ce7b64Ce5Ce3E5183bc94aD38"),
+        block_time_ms: 1_000,
+        rpc_env_var: "SONIC_RPC_URL",
+        explorer_url: "https://sonicscan.org",
+        limit_order_protocol: Some(LIMIT_ORDER_PROTOCOL),
+        true_erc20: Some(TRUE_ERC20),
+        permit2: Some(PERMIT2),
+        usdc: Some("0x29219dd400f2Bf60E5a23d13Be72B486D4038894"),
+        usdt: None,
+    },
+    ChainMetadata {
+        chain_id: ChainId::Unichain,
+        kind: ChainKind::Evm,
+        network_names: &["unichain"],
+        native_symbol: "ETH",
+        wrapped_native: Some("0x4200000000000000000000000000000000000006"),
+        block_time_ms: 1_000,
+        rpc_env_var: "UNICHAIN_RPC_URL",
+        explorer_url: "https://uniscan.xyz",
+        limit_order_protocol: Some(LIMIT_ORDER_PROTOCOL),
+        true_erc20: Some(TRUE_ERC20),
+        permit2: Some(PERMIT2),
+        usdc: Some("0x078D782b760474a361dDA0AF3839290b0EF57AD6"),
+        usdt: None,
+    },
+    ChainMetadata {
+        chain_id: ChainId::Solana,
+        kind: ChainKind::Solana,
+        network_names: &["solana", "sol"],
+        native_symbol: "SOL",
+        wrapped_native: Some("So11111111111111111111111111111111111111112"),
+        block_time_ms: 400,
+        rpc_env_var: "SOLANA_RPC_URL",
+        explorer_url: "https://solscan.io",
+        limit_order_protocol: None,
+        true_erc20: None,
+        permit2: None,
+        usdc: Some("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+        usdt: Some("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB"),
+    },
+    ChainMetadata {
+        chain_id: ChainId::Tron,
+        kind: ChainKind::Tron,
+        network_names: &["tron", "trx"],
+        native_symbol: "TRX",
+        wrapped_native: Some("TNUC9Qb1rRpS5CbWLmNMxXBjyFoydXjWFR"),
+        block_time_ms: 3_000,
+        // JSON-RPC endpoint of a Tron node (e.g. https://api.trongrid.io/jsonrpc),
+        // it serves reads only, see `tron::TronClient` for transactions
+        rpc_env_var: "TRON_RPC_URL",
+        explorer_url: "https://tronscan.org",
+        // no limit order protocol deployment is published for Tron yet
+        limit_order_protocol: None,
+        true_erc20: None,
+        permit2: None,
+        usdc: Some("TEkxiTehnzSmSe2XqrBj4w32RUN966rdz8"),
+        usdt: Some("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"),
+    },
+];
+
+impl ChainId {
+    pub fn metadata(&self) -> &'static ChainMetadata {
+        CHAINS
+            .iter()
+            .find(|metadata| metadata.chain_id == *self)
+            .expect("every chain id has metadata")
+    }
+
+    pub fn is_evm(&self) -> bool {
+        self.metadata().kind == ChainKind::Evm
+    }
+}
+
+impl ChainMetadata {
+    pub fn explorer_tx_url(&self, tx_hash: impl std::fmt::Display) -> String {
+        format!("{}/tx/{tx_hash}", self.explorer_url)
+    }
+
+    pub fn explorer_address_url(&self, address: impl std::fmt::Display) -> String {
+        let path = match self.kind {
+            ChainKind::Solana => "account",
+            ChainKind::Evm | ChainKind::Tron => "address",
+        };
+        format!("{}/{path}/{address}", self.explorer_url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use num_enum::TryFromPrimitive;
+
+    use super::*;
+    use crate::multichain_address::MultichainAddress;
+
+    #[test]
+    fn test_table_is_consistent() {
+        let mut names = HashSet::new();
+        for (idx, metadata) in CHAINS.iter().enumerate() {
+            assert!(
+                CHAINS[..idx]
+                    .iter()
+                    .all(|other| other.chain_id != metadata.chain_id)
+            );
+            assert_eq!(
+                ChainId::try_from_primitive(metadata.chain_id as u32).unwrap(),
+                metadata.chain_id
+            );
+            for name in metadata.network_names {
+                assert!(names.insert(*name), "duplicate network name {name}");
+                assert_eq!(ChainId::from_network_name(name).unwrap(), metadata.chain_id);
+            }
+
+            for address in [
+                metadata.wrapped_native,
+                metadata.limit_order_protocol,
+                metadata.true_erc20,
+                metadata.permit2,
+                metadata.usdc,
+                metadata.usdt,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                let address: MultichainAddress = address.parse().unwrap();
+                let kind = match address {
+                    MultichainAddress::Ethereum { .. } => ChainKind::Evm,
+                    MultichainAddress::Tron { .. } => ChainKind::Tron,
+                    MultichainAddress::Solana { .. } => ChainKind::Solana,
+                };
+                assert_eq!(kind, metadata.kind);
+            }
+        }
+    }
+
+    #[test]
+    fn test_explorer_urls() {
+        assert_eq!(
+            ChainId::Base.metadata().explorer_tx_url("0x01"),
+            "https://basescan.org/tx/0x01"
+        );
+        assert!(!ChainId::Solana.is_evm());
+        assert!(ChainId::Linea.is_evm());
+    }
+}
@@ -1,22 +1,38 @@
-use alloy::primitives::{B256, Bytes};
+use alloy::{
+    dyn_abi::Eip712Domain,
+    primitives::{B256, Bytes},
+    providers::Provider,
+    signers::Signer,
+};
 use chrono::Utc;
 use rand::Rng;
 
 use crate::{
-    addresses::get_true_erc20_address,
+    addresses::{try_get_limit_order_contract_address, try_get_true_erc20_address},
+    api::types::OrderType,
     chain_id::ChainId,
-    constants::UINT_40_MAX,
+    constants::{NATIVE_CURRENCY, UINT_40_MAX, UINT_160_MAX},
     escrow_extension::{EscrowExtension, EscrowParams},
     fusion::{
         auction_details::{AuctionDetails, AuctionWhitelistItem},
-        fusion_order::{FusionOrder, FusionOrderExtra, IntegratorFee},
+        fusion_order::{Fees, FusionOrder, FusionOrderExtra, IntegratorFee, ResolverFee},
         settlement_post_interaction::{SettlementPostInteractionData, SettlementSuffixData},
     },
     hash_lock::HashLock,
-    limit::{eip712::LimitOrderV4, interaction::Interaction, order_info::OrderInfoData},
+    limit::{
+        eip712::{LimitOrderV4, try_get_limit_order_v4_domain},
+        epoch_manager::SeriesEpoch,
+        extension::Extension,
+        interaction::Interaction,
+        limit_order::LimitOrder,
+        order_info::OrderInfoData,
+        permit::MakerPermit,
+        signature::{OrderSignature, verify_eoa_signature},
+    },
     multichain_address::MultichainAddress,
+    order_summary::{AuctionPointSummary, AuctionSummary, OrderSummary, WhitelistSummary},
     quote::{QuoteRequest, QuoteResult, preset::PresetType},
-    utils::bps::Bps,
+    utils::alloy::CustomAlloy,
 };
 
 #[derive(Clone, Debug)]
@@ -25,6 +41,8 @@ pub struct PreparedOrder {
     pub order: CrossChainOrder,
     pub hash: B256,
     pub quote_id: String,
+    // Hashes of the order secrets in fill order, checked against the hash lock
+    pub secret_hashes: Vec<B256>,
 }
 
 impl PreparedOrder {
@@ -38,6 +56,53 @@ impl PreparedOrder {
                 "request quote with enableEstimate=true",
             ));
         };
+        if quote_request.src_token_address == NATIVE_CURRENCY {
+            return Err(crate::Error::InternalErrorStr(
+                "native currency can not be the src token, use QuoteRequest::with_wrapped_native_src",
+            ));
+        }
+
+        if !HashLock::from_secret_hashes(&order_params.secret_hashes)
+            .is_ok_and(|hash_lock| hash_lock.value() == order_params.hash_lock.value())
+        {
+            return Err(crate::Error::InvalidSecretHashes(format!(
+                "{} secret hashes do not match hash lock {}",
+                order_params.secret_hashes.len(),
+                order_params.hash_lock.value()
+            )));
+        }
+
+        validate_receiver(order_params.dst_address, quote_request.dst_chain_id)?;
+
+        // the quote deducts the integrator fee from the amounts, so the order has to charge
+        // exactly the fee the quote was requested with
+        let fee_bps = order_params
+            .fee
+            .as_ref()
+            .map(|fee| fee.taking_fee_bps as u64)
+            .unwrap_or_default();
+        if fee_bps != quote_request.fee.unwrap_or_default() {
+            return Err(crate::Error::InvalidFee(format!(
+                "order charges {fee_bps} bps, the quote was requested with {} bps, see QuoteRequest::with_fee",
+                quote_request.fee.unwrap_or_default()
+            )));
+        }
+        if let Some(fee) = &order_params.fee
+            && fee.taking_fee_bps > 0
+            && fee.taking_fee_receiver == MultichainAddress::ZERO
+        {
+            return Err(crate::Error::InvalidFee(
+                "integrator fee receiver is not set".to_string(),
+            ));
+        }
+        if let Some(resolver_fee) = &order_params.resolver_fee
+            && u32::try_from(resolver_fee.bank_fee).is_err()
+        {
+            return Err(crate::Error::InvalidFee(format!(
+                "resolver fee {} does not fit in u32",
+                resolver_fee.bank_fee
+            )));
+        }
 
         let order = CrossChainOrder::from_quote(
             quote_request,
@@ -47,31 +112,133 @@ impl PreparedOrder {
                 preset: order_params.preset,
                 receiver: Some(order_params.dst_address),
                 nonce: None, // Some(0),
-                permit: None,
-                is_permit_2: false,
-                taking_fee_receiver: order_params.fee.as_ref().map(|fee| fee.taking_fee_receiver),
+                epoch: order_params.epoch,
+                permit: order_params
+                    .permit
+                    .as_ref()
+                    .map(|permit| permit.data.clone()),
+                is_permit_2: order_params
+                    .permit
+                    .as_ref()
+                    .is_some_and(|permit| permit.is_permit2),
+                integrator_fee: order_params
+                    .fee
+                    .as_ref()
+                    .map(|fee| {
+                        IntegratorFee::from_bps(fee.taking_fee_receiver, fee.taking_fee_bps as u64)
+                    })
+                    .transpose()?,
+                resolver_fee: order_params.resolver_fee,
                 delay_auction_start_time_by: None,
                 order_expiration_delay: None,
             },
-        );
+        )?;
 
-        let hash = order.get_order_hash(quote_request.src_chain_id);
+        let hash = order.get_order_hash(quote_request.src_chain_id)?;
 
         Ok(PreparedOrder {
             src_chain_id: quote_request.src_chain_id,
             order,
             hash,
             quote_id: quote_id.clone(),
+            secret_hashes: order_params.secret_hashes,
         })
     }
 
-    pub fn eip712_signing_hash(&self) -> B256 {
+    pub fn eip712_signing_hash(&self) -> crate::Result<B256> {
         self.order.inner.get_order_hash(self.src_chain_id)
     }
 
+    pub fn eip712_domain(&self) -> crate::Result<Eip712Domain> {
+        try_get_limit_order_v4_domain(self.src_chain_id)
+    }
+
     pub fn to_v4(&self) -> LimitOrderV4 {
         self.order.inner.inner.to_v4()
     }
+
+    // Signs the typed order rather than its hash, so wallets implementing
+    // `sign_typed_data` (e.g. hardware wallets) can show the order to the user
+    pub async fn sign(&self, signer: &(impl Signer + Sync)) -> crate::Result<OrderSignature> {
+        let order = self.to_v4();
+        if signer.address() != order.maker {
+            return Err(crate::Error::InvalidSignature(format!(
+                "signer {} is not the order maker {}",
+                signer.address(),
+                order.maker
+            )));
+        }
+
+        let signature = signer
+            .sign_typed_data(&order, &self.eip712_domain()?)
+            .await
+            .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+        verify_eoa_signature(&signature, order.maker, self.hash)?;
+
+        Ok(OrderSignature::Eoa(signature))
+    }
+
+    // EIP-1271 path for makers that are contracts which accept ECDSA signatures of
+    // their owner over the order hash
+    pub async fn sign_as_contract_owner(
+        &self,
+        owner: &(impl Signer + Sync),
+    ) -> crate::Result<OrderSignature> {
+        let signature = owner
+            .sign_typed_data(&self.to_v4(), &self.eip712_domain()?)
+            .await
+            .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+
+        Ok(OrderSignature::Contract(signature.as_bytes().into()))
+    }
+
+    // EIP-1271 path for any other maker contract, `signature` is checked with the
+    // contract's `isValidSignature` before it is accepted
+    pub async fn contract_signature(
+        &self,
+        provider: &impl Provider,
+        signature: Bytes,
+    ) -> crate::Result<OrderSignature> {
+        let signature = OrderSignature::Contract(signature);
+        signature
+            .verify(provider, self.to_v4().maker, self.hash)
+            .await?;
+
+        Ok(signature)
+    }
+}
+
+// Receivers are written to the order as raw bytes, an address of another chain would
+// send the funds to whoever owns these bytes on the destination chain
+fn validate_receiver(receiver: MultichainAddress, dst_chain_id: ChainId) -> crate::Result<()> {
+    let invalid = |reason: &str| {
+        Err(crate::Error::InvalidReceiver(format!(
+            "{receiver} on {dst_chain_id}: {reason}"
+        )))
+    };
+
+    if receiver.as_bytes32().is_zero() {
+        return invalid("zero address");
+    }
+    match (receiver, dst_chain_id) {
+        (MultichainAddress::Solana { .. }, ChainId::Solana) => Ok(()),
+        (_, ChainId::Solana) => invalid("expected a 32 byte Solana address"),
+        (MultichainAddress::Solana { .. }, _) => invalid("Solana address on another chain"),
+        (MultichainAddress::Tron { .. }, ChainId::Tron) => Ok(()),
+        (MultichainAddress::Tron { .. }, _) => invalid("Tron address on another chain"),
+        (
+            MultichainAddress::Ethereum {
+                chain_id: Some(chain_id),
+                ..
+            },
+            _,
+        ) if chain_id != dst_chain_id => invalid(&format!("address is for {chain_id}")),
+        // an untagged hex address is most likely an EVM address, which nobody controls on Tron
+        (MultichainAddress::Ethereum { chain_id: None, .. }, ChainId::Tron) => {
+            invalid("expected a Tron address")
+        }
+        (MultichainAddress::Ethereum { .. }, _) => Ok(()),
+    }
 }
 
 #[derive(Debug)]
@@ -80,7 +247,14 @@ pub struct CrossChainOrderParams {
     pub hash_lock: HashLock,
     pub secret_hashes: Vec<B256>,
     pub fee: Option<Fee>,
+    // Charged from the resolver's fee bank on fill, the settlement data holds it as a u32
+    pub resolver_fee: Option<ResolverFee>,
     pub preset: Option<PresetType>,
+    // Builds the order in epoch-managed mode, so `increase_epoch` can cancel it together
+    // with every other order of the series. Read the current value with `get_epoch`
+    pub epoch: Option<SeriesEpoch>,
+    // Signed `Eip2612Permit` or `Permit2Single`, replaces the on-chain approve
+    pub permit: Option<MakerPermit>,
 }
 
 #[derive(Debug)]
@@ -95,27 +269,25 @@ pub struct CrossChainOrderParamsData {
     preset: Option<PresetType>,
     receiver: Option<MultichainAddress>,
     nonce: Option<u64>,
+    epoch: Option<SeriesEpoch>,
     permit: Option<Bytes>,
     is_permit_2: bool,
-    taking_fee_receiver: Option<MultichainAddress>,
+    integrator_fee: Option<IntegratorFee>,
+    resolver_fee: Option<ResolverFee>,
     delay_auction_start_time_by: Option<u64>,
     order_expiration_delay: Option<u64>,
 }
 
-pub struct DetailsFees {
-    integrator_fee: IntegratorFee,
-    bank_fee: Option<u64>,
-}
-
 pub struct Details {
     auction: AuctionDetails,
-    fees: Option<DetailsFees>,
+    fees: Option<Fees>,
     whitelist: Vec<AuctionWhitelistItem>,
     resolving_start_time: Option<u64>,
 }
 
 pub struct CrossChainExtra {
     nonce: Option<u64>,
+    epoch: Option<SeriesEpoch>,
     permit: Option<Bytes>,
     // Order will expire in `orderExpirationDelay` after auction ends Default 12s
     order_expiration_delay: Option<u64>,
@@ -135,7 +307,10 @@ impl CrossChainOrder {
         quote_request: &QuoteRequest,
         quote_result: &QuoteResult,
         params: CrossChainOrderParamsData,
-    ) -> CrossChainOrder {
+    ) -> crate::Result<CrossChainOrder> {
+        // the order is hashed against the limit order protocol of the source chain
+        try_get_limit_order_contract_address(quote_request.src_chain_id)?;
+
         let preset = params
             .preset
             .and_then(|preset| quote_result.get_preset(preset))
@@ -147,6 +322,20 @@ impl CrossChainOrder {
         let allow_multiple_fills = preset.allow_multiple_fills;
         let is_nonce_required = !allow_partial_fills || !allow_multiple_fills;
 
+        if params.epoch.is_some() {
+            if is_nonce_required {
+                return Err(crate::Error::InvalidMakerTraits(
+                    "Epoch manager allowed only when partialFills and multipleFills enabled"
+                        .to_string(),
+                ));
+            }
+            if params.nonce.is_some() {
+                return Err(crate::Error::InvalidMakerTraits(
+                    "nonce and epoch share the same bits, set only one of them".to_string(),
+                ));
+            }
+        }
+
         let nonce = if is_nonce_required {
             params.nonce.or_else(|| {
                 let mut rng = rand::rng();
@@ -184,18 +373,16 @@ impl CrossChainOrder {
             },
             Details {
                 auction: auction_details,
-                fees: Some(DetailsFees {
-                    integrator_fee: IntegratorFee {
-                        receiver: params.taking_fee_receiver.unwrap_or_default(),
-                        ratio: Bps::to_ratio_format(quote_request.fee),
-                    },
-                    bank_fee: Some(0),
+                fees: Some(Fees {
+                    integrator: params.integrator_fee,
+                    resolver: params.resolver_fee,
                 }),
                 whitelist,
                 resolving_start_time: None,
             },
             Some(CrossChainExtra {
                 nonce,
+                epoch: params.epoch,
                 permit: params.permit,
                 order_expiration_delay: params.order_expiration_delay,
                 enable_permit2: Some(params.is_permit_2),
@@ -212,10 +399,14 @@ impl CrossChainOrder {
         escrow_params: EscrowParams,
         details: Details,
         extra: Option<CrossChainExtra>,
-    ) -> Self {
+    ) -> crate::Result<Self> {
         let post_interaction_data = SettlementPostInteractionData::new(SettlementSuffixData {
-            bank_fee: details.fees.as_ref().and_then(|f| f.bank_fee),
-            integrator_fee: details.fees.as_ref().map(|f| f.integrator_fee.clone()),
+            bank_fee: details
+                .fees
+                .as_ref()
+                .and_then(|f| f.resolver.as_ref())
+                .map(|f| f.bank_fee),
+            integrator_fee: details.fees.as_ref().and_then(|f| f.integrator.clone()),
             whitelist: details.whitelist,
             resolving_start_time: details
                 .resolving_start_time
@@ -228,7 +419,7 @@ impl CrossChainOrder {
             "src and dst chain ids must be different"
         );
 
-        let true_erc20 = get_true_erc20_address(escrow_params.src_chain_id);
+        let true_erc20 = try_get_true_erc20_address(escrow_params.src_chain_id)?;
 
         let ext = EscrowExtension::new(
             src_escrow_factory,
@@ -243,7 +434,8 @@ impl CrossChainOrder {
                 }),
             order_info.taker_asset,
             escrow_params,
-        );
+        )
+        .with_dst_receiver(order_info.receiver.unwrap_or(order_info.maker));
 
         Self::new_from_extension(ext, order_info.with_taker_asset(true_erc20), extra)
     }
@@ -252,8 +444,8 @@ impl CrossChainOrder {
         extension: EscrowExtension,
         order_info: OrderInfoData,
         extra: Option<CrossChainExtra>,
-    ) -> Self {
-        Self {
+    ) -> crate::Result<Self> {
+        Ok(Self {
             inner: FusionOrder::new_with_extension(
                 extension.fusion_extension.settlement_extension_contract,
                 order_info,
@@ -261,13 +453,128 @@ impl CrossChainOrder {
                 extension.fusion_extension.post_interaction_data.clone(),
                 extra.map(Into::into),
                 extension,
-            ),
+            )?,
+        })
+    }
+
+    // Reconstructs an order built elsewhere, e.g. returned by `get_active_orders` or `get_order_status`
+    pub fn from_data_and_extension(order: &LimitOrderV4, extension: Bytes) -> crate::Result<Self> {
+        let decoded_extension = Extension::decode_from(extension)?;
+
+        let extension_hash = decoded_extension.keccak256().to_u256() & UINT_160_MAX;
+        if order.salt & UINT_160_MAX != extension_hash {
+            return Err(crate::Error::Decode {
+                field: "salt",
+                offset: 0,
+                reason: "lowest 160 bits of salt should be extension hash".to_string(),
+            });
         }
+
+        let extension = EscrowExtension::from_extension(decoded_extension)?;
+
+        Ok(Self {
+            inner: FusionOrder {
+                settlement_extension_contract: extension
+                    .fusion_extension
+                    .settlement_extension_contract,
+                extension,
+                inner: LimitOrder::from_v4(order),
+            },
+        })
     }
 
-    pub fn get_order_hash(&self, src_chain_id: ChainId) -> B256 {
+    pub fn get_order_hash(&self, src_chain_id: ChainId) -> crate::Result<B256> {
         self.inner.get_order_hash(src_chain_id)
     }
+
+    pub fn describe(&self, src_chain_id: ChainId) -> crate::Result<OrderSummary> {
+        let order = &self.inner.inner;
+        let extension = &self.inner.extension;
+        let auction = &extension.fusion_extension.auction_details;
+        let post_interaction = &extension.fusion_extension.post_interaction_data;
+        let maker_traits = &order.maker_traits;
+
+        let receiver = post_interaction.custom_receiver.unwrap_or(
+            if order.receiver == MultichainAddress::ZERO {
+                order.maker
+            } else {
+                order.receiver
+            },
+        );
+        let receiver = extension.dst_receiver(receiver).unwrap_or(receiver);
+
+        let mut point_time = auction.start_time;
+        let points = auction
+            .points
+            .iter()
+            .map(|point| {
+                point_time += point.delay;
+                AuctionPointSummary {
+                    timestamp: point_time,
+                    delay: point.delay,
+                    coefficient: point.coefficient,
+                }
+            })
+            .collect();
+
+        let mut allow_from = post_interaction.resolving_start_time;
+        let whitelist = post_interaction
+            .whitelist
+            .iter()
+            .map(|item| {
+                allow_from += item.delay;
+                WhitelistSummary {
+                    address_half: item.address_half,
+                    delay: item.delay,
+                    allow_from,
+                }
+            })
+            .collect();
+
+        let (hash_lock_type, secrets_count) = if maker_traits.is_multiple_fills_allowed() {
+            (
+                OrderType::MultipleFills,
+                extension.hash_lock_info.parts_count() + 1,
+            )
+        } else {
+            (OrderType::SingleFill, 1)
+        };
+
+        Ok(OrderSummary {
+            order_hash: self.get_order_hash(src_chain_id)?,
+            maker: order.maker,
+            receiver,
+            src_chain_id,
+            dst_chain_id: extension.dst_chain_id,
+            src_token: order.maker_asset,
+            dst_token: extension.dst_token,
+            making_amount: order.making_amount,
+            min_taking_amount: order.taking_amount,
+            auction: AuctionSummary {
+                start_time: auction.start_time,
+                end_time: auction.start_time + auction.duration,
+                initial_rate_bump: auction.initial_rate_bump,
+                points,
+                gas_bump_estimate: auction.gas_cost.gas_bump_estimate,
+                gas_price_estimate: auction.gas_cost.gas_price_estimate,
+            },
+            resolving_start_time: post_interaction.resolving_start_time,
+            whitelist,
+            integrator_fee: post_interaction.integrator_fee.clone(),
+            integrator_fee_amount: post_interaction
+                .integrator_fee
+                .as_ref()
+                .map(|fee| fee.fee_amount(order.taking_amount)),
+            bank_fee: post_interaction.bank_fee,
+            maker_traits: maker_traits.into(),
+            time_locks: extension.time_locks.clone(),
+            hash_lock: extension.hash_lock_info.value(),
+            hash_lock_type,
+            secrets_count,
+            src_safety_deposit: extension.src_safety_deposit,
+            dst_safety_deposit: extension.dst_safety_deposit,
+        })
+    }
 }
 
 impl From<CrossChainExtra> for FusionOrderExtra {
@@ -275,6 +582,7 @@ impl From<CrossChainExtra> for FusionOrderExtra {
         Self {
             unwrap_weth: None,
             nonce: extra.nonce,
+            epoch: extra.epoch,
             permit: extra.permit,
             allow_partial_fills: extra.allow_partial_fills,
             allow_multiple_fills: extra.allow_multiple_fills,
@@ -285,3 +593,607 @@ impl From<CrossChainExtra> for FusionOrderExtra {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        primitives::{Address, B256, Bytes, U256},
+        signers::local::PrivateKeySigner,
+    };
+
+    use crate::{
+        addresses::{get_wrapped_native_address, usdc},
+        api::types::OrderType,
+        chain_id::ChainId,
+        constants::{NATIVE_CURRENCY, UINT_40_MAX},
+        cross_chain_order::{
+            CrossChainOrder, CrossChainOrderParams, Fee, PreparedOrder, validate_receiver,
+        },
+        fusion::fusion_order::{IntegratorFee, ResolverFee},
+        hash_lock::HashLock,
+        limit::{
+            eip712::LimitOrderV4, epoch_manager::SeriesEpoch,
+            extension_builder::ExtensionBuildable, maker_traits::MakerTraits,
+            order_info::OrderInfoData, permit::MakerPermit, signature::OrderSignature,
+        },
+        multichain_address::MultichainAddress,
+        quote::{QuoteRequest, preset::PresetType},
+        test_utils::{active_order_fixture, quote_fixture},
+    };
+
+    fn order_params(preset: PresetType, epoch: Option<SeriesEpoch>) -> CrossChainOrderParams {
+        let (request, result) = quote_fixture();
+        let secrets = vec![B256::repeat_byte(1); result.get_preset(preset).unwrap().secrets_count];
+
+        CrossChainOrderParams {
+            dst_address: request.maker_address,
+            hash_lock: HashLock::from_secrets(&secrets).unwrap(),
+            secret_hashes: secrets.iter().map(HashLock::hash_secret).collect(),
+            fee: None,
+            resolver_fee: None,
+            preset: Some(preset),
+            epoch,
+            permit: None,
+        }
+    }
+
+    fn assert_round_trip(
+        order: &LimitOrderV4,
+        extension: Bytes,
+        src_chain_id: ChainId,
+        hash: B256,
+    ) {
+        let decoded = CrossChainOrder::from_data_and_extension(order, extension.clone()).unwrap();
+
+        assert_eq!(decoded.get_order_hash(src_chain_id).unwrap(), hash);
+        assert_eq!(decoded.inner.inner.to_v4(), *order);
+        assert_eq!(decoded.inner.extension.build().encode(), extension);
+    }
+
+    #[test]
+    fn test_from_active_order_fixture() {
+        let order = active_order_fixture();
+
+        assert_round_trip(
+            &order.order,
+            order.extension.clone(),
+            order.src_chain_id,
+            order.order_hash,
+        );
+
+        let decoded =
+            CrossChainOrder::from_data_and_extension(&order.order, order.extension).unwrap();
+        assert_eq!(decoded.inner.extension.dst_chain_id, order.dst_chain_id);
+        assert_eq!(
+            decoded.inner.settlement_extension_contract.to_string(),
+            "0xa7bCb4EAc8964306F9e3764f67Db6A7af6DdF99A"
+        );
+    }
+
+    #[test]
+    fn test_from_submitted_order_fixture() {
+        let order: LimitOrderV4 = serde_json::from_value(serde_json::json!({
+            "salt": "59760570324334168870571149440013523821369857597971969449377342064151251341468",
+            "maker": "0x5bc44f18b91f55540d11d612c08e4faad619eb55",
+            "receiver": "0x0000000000000000000000000000000000000000",
+            "makerAsset": "0xaf88d065e77c8cc2239327c5edb3a432268e5831",
+            "takerAsset": "0xda0000d4000015a526378bb6fafc650cea5966f8",
+            "makingAmount": "1000000",
+            "takingAmount": "953765",
+            "makerTraits": "62419173104490761595518734107164453327180858579398760566479924277126082068480"
+        }))
+        .unwrap();
+
+        assert_round_trip(
+            &order,
+            "0x0000013b0000005e0000005e0000005e0000005e0000002f0000000000000000a7bcb4eac8964306f9e3764f67db6a7af6ddf99a0208630000000a688e4ddb0000b404059802ea9b0078020863003ca7bcb4eac8964306f9e3764f67db6a7af6ddf99a0208630000000a688e4ddb0000b404059802ea9b0078020863003ca7bcb4eac8964306f9e3764f67db6a7af6ddf99a688e4dcaf0fc4b2dbe711e518acb000072f8a0c8c415454f629c00005ba74b09ae44e823cf7700001837c320f7b010ff48cc9fa5f651640cfce1adc48a301886abd688cc8117d74d79000000000000000000000000000000000000000000000000000000000000000a0000000000000000000000000b2c639c533813f4aa9d7837caf62653d097ff850000000000000000000002066afc9f0000000000000000000000006e07c36b1000000000000001e0000001680000003c000002b800000240000001a40000003c".parse().unwrap(),
+            ChainId::Arbitrum,
+            "0x8268f083d495f9c437cd9180ade511f6b28af2a7710b1dc9d2c1a88a15fd5020".parse().unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_salt_mismatch() {
+        let order = LimitOrderV4::default();
+        let err = CrossChainOrder::from_data_and_extension(
+            &order,
+            "0x0000000100000001000000010000000100000001000000010000000100000001ff"
+                .parse()
+                .unwrap(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, crate::Error::Decode { field: "salt", .. }));
+    }
+
+    #[test]
+    fn test_expiration() {
+        let fixture = active_order_fixture();
+        let decoded =
+            CrossChainOrder::from_data_and_extension(&fixture.order, fixture.extension).unwrap();
+        let auction = decoded
+            .inner
+            .extension
+            .fusion_extension
+            .auction_details
+            .clone();
+        let order = &decoded.inner.inner;
+
+        let rebuilt = CrossChainOrder::new_from_extension(
+            decoded.inner.extension.clone(),
+            OrderInfoData {
+                maker_asset: order.maker_asset,
+                taker_asset: order.taker_asset,
+                making_amount: order.making_amount,
+                taking_amount: order.taking_amount,
+                maker: order.maker,
+                receiver: None,
+                salt: None,
+            },
+            None,
+        )
+        .unwrap();
+
+        // order expires 12s (default expiration delay) after the auction ends
+        assert_eq!(
+            rebuilt.inner.inner.maker_traits.expiration(),
+            auction.start_time + auction.duration + 12
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        let order = active_order_fixture();
+        let summary = CrossChainOrder::from_data_and_extension(&order.order, order.extension)
+            .unwrap()
+            .describe(order.src_chain_id)
+            .unwrap();
+
+        assert_eq!(summary.order_hash, order.order_hash);
+        assert_eq!(summary.receiver, summary.maker);
+        assert_eq!(summary.dst_chain_id, ChainId::Optimism);
+        assert_eq!(
+            summary.dst_token.to_string(),
+            "0x0b2C639c533813f4Aa9D7837CAf62653d097Ff85"
+        );
+        assert_eq!(summary.auction.start_time, 0x688b2c26);
+        assert_eq!(summary.auction.end_time, 0x688b2c26 + 180);
+        assert_eq!(
+            summary
+                .auction
+                .points
+                .iter()
+                .map(|p| p.timestamp)
+                .collect::<Vec<_>>(),
+            vec![0x688b2c26 + 120, 0x688b2c26 + 180]
+        );
+        assert_eq!(summary.whitelist.len(), 3);
+        assert!(summary.integrator_fee.is_none());
+        assert!(!summary.maker_traits.allow_multiple_fills);
+        assert!(summary.maker_traits.has_extension);
+        assert!(matches!(summary.hash_lock_type, OrderType::SingleFill));
+        assert_eq!(summary.time_locks.dst_cancellation(), 480);
+
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["srcChainId"], 42161);
+        assert_eq!(json["makerTraits"]["allowPartialFills"], false);
+        assert_eq!(json["timeLocks"]["srcWithdrawal"], 60);
+
+        let report = summary.to_string();
+        assert!(report.contains("route:              arb -> op"));
+        assert!(report.contains("dst cancellation        +480s"));
+    }
+
+    #[test]
+    fn test_epoch_managed_order() {
+        let (request, result) = quote_fixture();
+        let epoch = SeriesEpoch {
+            series: 2,
+            epoch: 5,
+        };
+
+        let order = PreparedOrder::from_quote(
+            &request,
+            &result,
+            order_params(PresetType::Fast, Some(epoch)),
+        )
+        .unwrap();
+        let maker_traits = MakerTraits::new(order.to_v4().makerTraits);
+        assert!(maker_traits.need_check_epoch_manager());
+        assert_eq!(maker_traits.series(), 2);
+        assert_eq!(maker_traits.nonce_or_epoch(), 5);
+        assert!(maker_traits.validate().is_ok());
+
+        let order =
+            PreparedOrder::from_quote(&request, &result, order_params(PresetType::Fast, None))
+                .unwrap();
+        assert!(!MakerTraits::new(order.to_v4().makerTraits).need_check_epoch_manager());
+
+        // epochs need partial and multiple fills, the slow preset allows neither
+        let err = PreparedOrder::from_quote(
+            &request,
+            &result,
+            order_params(PresetType::Slow, Some(epoch)),
+        )
+        .unwrap_err();
+        assert!(matches!(err, crate::Error::InvalidMakerTraits(_)));
+
+        // series and epoch are uint40 fields of the maker traits
+        for epoch in [
+            SeriesEpoch {
+                series: UINT_40_MAX + 1,
+                epoch: 5,
+            },
+            SeriesEpoch {
+                series: 2,
+                epoch: UINT_40_MAX + 1,
+            },
+        ] {
+            let err = PreparedOrder::from_quote(
+                &request,
+                &result,
+                order_params(PresetType::Fast, Some(epoch)),
+            )
+            .unwrap_err();
+            assert!(matches!(err, crate::Error::InvalidMakerTraits(_)));
+        }
+    }
+
+    #[test]
+    fn test_order_with_permit() {
+        let (request, result) = quote_fixture();
+        let permit = MakerPermit {
+            data: Bytes::from(vec![7u8; 352]),
+            is_permit2: true,
+        };
+
+        let order = PreparedOrder::from_quote(
+            &request,
+            &result,
+            CrossChainOrderParams {
+                permit: Some(permit.clone()),
+                ..order_params(PresetType::Fast, None)
+            },
+        )
+        .unwrap();
+
+        assert!(MakerTraits::new(order.to_v4().makerTraits).is_permit2());
+        let maker_permit = order
+            .order
+            .inner
+            .extension
+            .fusion_extension
+            .maker_permit
+            .as_ref()
+            .unwrap();
+        assert_eq!(maker_permit.target, request.src_token_address);
+        assert_eq!(maker_permit.data, permit.data);
+
+        // the permit is part of the extension, so it survives a decode round trip
+        let v4 = order.to_v4();
+        let extension = order.order.inner.extension.build().encode();
+        let decoded = CrossChainOrder::from_data_and_extension(&v4, extension).unwrap();
+        assert_eq!(
+            decoded
+                .inner
+                .extension
+                .fusion_extension
+                .maker_permit
+                .unwrap()
+                .data,
+            permit.data
+        );
+    }
+
+    #[test]
+    fn test_integrator_fee() {
+        let (request, result) = quote_fixture();
+        let request = request.with_fee(50);
+        let fee_receiver: MultichainAddress = "0x00000000000000000000000000000000deadbeef"
+            .parse()
+            .unwrap();
+
+        let order = PreparedOrder::from_quote(
+            &request,
+            &result,
+            CrossChainOrderParams {
+                fee: Some(Fee {
+                    taking_fee_bps: 50,
+                    taking_fee_receiver: fee_receiver,
+                }),
+                ..order_params(PresetType::Fast, None)
+            },
+        )
+        .unwrap();
+
+        // the dst escrow pays the order receiver (zero for the maker), the fee does not move
+        // it to the escrow factory
+        assert_eq!(order.to_v4().receiver, Address::ZERO);
+        let summary = CrossChainOrder::from_data_and_extension(
+            &order.to_v4(),
+            order.order.inner.extension.build().encode(),
+        )
+        .unwrap()
+        .describe(request.src_chain_id)
+        .unwrap();
+        assert_eq!(summary.receiver, request.maker_address);
+        assert_eq!(
+            summary.min_taking_amount,
+            result.recommended_preset().auction_end_amount
+        );
+        assert_eq!(
+            summary.integrator_fee_amount,
+            Some(summary.min_taking_amount * U256::from(5) / U256::from(1000))
+        );
+        assert_eq!(summary.bank_fee, None);
+        let integrator_fee = summary.integrator_fee.unwrap();
+        assert_eq!(
+            integrator_fee,
+            IntegratorFee::from_bps(fee_receiver, 50).unwrap()
+        );
+        assert_eq!(integrator_fee.ratio, 500);
+        assert_eq!(
+            integrator_fee.fee_amount(U256::from(10_000_000)),
+            U256::from(50_000)
+        );
+
+        // the quote was requested without the fee
+        let err = PreparedOrder::from_quote(
+            &QuoteRequest {
+                fee: None,
+                ..request.clone()
+            },
+            &result,
+            CrossChainOrderParams {
+                fee: Some(Fee {
+                    taking_fee_bps: 50,
+                    taking_fee_receiver: fee_receiver,
+                }),
+                ..order_params(PresetType::Fast, None)
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, crate::Error::InvalidFee(_)));
+
+        let err = PreparedOrder::from_quote(
+            &request,
+            &result,
+            CrossChainOrderParams {
+                fee: Some(Fee {
+                    taking_fee_bps: 50,
+                    taking_fee_receiver: MultichainAddress::ZERO,
+                }),
+                ..order_params(PresetType::Fast, None)
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, crate::Error::InvalidFee(_)));
+    }
+
+    #[test]
+    fn test_resolver_fee() {
+        let (request, result) = quote_fixture();
+        let with_resolver_fee = |bank_fee| CrossChainOrderParams {
+            resolver_fee: Some(ResolverFee { bank_fee }),
+            ..order_params(PresetType::Fast, None)
+        };
+
+        let order = PreparedOrder::from_quote(&request, &result, with_resolver_fee(1000)).unwrap();
+        assert_eq!(order.to_v4().receiver, Address::ZERO);
+        let summary = CrossChainOrder::from_data_and_extension(
+            &order.to_v4(),
+            order.order.inner.extension.build().encode(),
+        )
+        .unwrap()
+        .describe(request.src_chain_id)
+        .unwrap();
+        assert_eq!(summary.bank_fee, Some(1000));
+        assert!(summary.integrator_fee.is_none());
+
+        let err =
+            PreparedOrder::from_quote(&request, &result, with_resolver_fee(u32::MAX as u64 + 1))
+                .unwrap_err();
+        assert!(matches!(err, crate::Error::InvalidFee(_)));
+    }
+
+    #[test]
+    fn test_receiver_validation() {
+        let (request, result) = quote_fixture();
+        let receiver: MultichainAddress = "0x00000000000000000000000000000000deadbeef"
+            .parse()
+            .unwrap();
+        let with_receiver = |dst_address| CrossChainOrderParams {
+            dst_address,
+            ..order_params(PresetType::Fast, None)
+        };
+
+        // without an integrator fee the receiver is only in the order
+        let order = PreparedOrder::from_quote(&request, &result, with_receiver(receiver)).unwrap();
+        assert_eq!(order.to_v4().receiver, receiver.low_20_bytes());
+        let post_interaction = &order
+            .order
+            .inner
+            .extension
+            .fusion_extension
+            .post_interaction_data;
+        assert_eq!(post_interaction.custom_receiver, None);
+        let decoded = CrossChainOrder::from_data_and_extension(
+            &order.to_v4(),
+            order.order.inner.extension.build().encode(),
+        )
+        .unwrap();
+        assert_eq!(
+            decoded
+                .inner
+                .extension
+                .fusion_extension
+                .post_interaction_data,
+            *post_interaction
+        );
+
+        for dst_address in [
+            MultichainAddress::ZERO,
+            "TJLRfJUAHPRxoizJeyYFFZ7nEHit4L9FfE".parse().unwrap(),
+            "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+                .parse()
+                .unwrap(),
+            MultichainAddress::Ethereum {
+                raw: receiver.as_raw(),
+                chain_id: Some(ChainId::Arbitrum),
+            },
+        ] {
+            let err = PreparedOrder::from_quote(&request, &result, with_receiver(dst_address))
+                .unwrap_err();
+            assert!(
+                matches!(err, crate::Error::InvalidReceiver(_)),
+                "{dst_address}"
+            );
+        }
+
+        let err = PreparedOrder::from_quote(
+            &QuoteRequest {
+                dst_chain_id: ChainId::Solana,
+                dst_token_address: usdc(ChainId::Solana),
+                ..request
+            },
+            &result,
+            with_receiver(receiver),
+        )
+        .unwrap_err();
+        assert!(matches!(err, crate::Error::InvalidReceiver(_)));
+
+        let tron: MultichainAddress = "TJLRfJUAHPRxoizJeyYFFZ7nEHit4L9FfE".parse().unwrap();
+        validate_receiver(tron, ChainId::Tron).unwrap();
+        validate_receiver(
+            MultichainAddress::Ethereum {
+                raw: tron.as_raw(),
+                chain_id: Some(ChainId::Tron),
+            },
+            ChainId::Tron,
+        )
+        .unwrap();
+        assert!(matches!(
+            validate_receiver(receiver, ChainId::Tron),
+            Err(crate::Error::InvalidReceiver(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sign() {
+        let signer: PrivateKeySigner =
+            "0x0123456789012345678901234567890123456789012345678901234567890123"
+                .parse()
+                .unwrap();
+        let (request, result) = quote_fixture();
+        let request = QuoteRequest {
+            maker_address: signer.address().into(),
+            ..request
+        };
+
+        let order =
+            PreparedOrder::from_quote(&request, &result, order_params(PresetType::Fast, None))
+                .unwrap();
+        let signature = order.sign(&signer).await.unwrap();
+        assert!(!signature.is_maker_contract());
+        assert_eq!(signature.to_bytes().len(), 65);
+        let OrderSignature::Eoa(ecdsa) = signature else {
+            panic!("expected an ECDSA signature");
+        };
+        assert_eq!(
+            ecdsa
+                .recover_address_from_prehash(&order.eip712_signing_hash().unwrap())
+                .unwrap(),
+            signer.address()
+        );
+
+        let other = PrivateKeySigner::random();
+        assert!(matches!(
+            order.sign(&other).await,
+            Err(crate::Error::InvalidSignature(_))
+        ));
+
+        let contract_signature = order.sign_as_contract_owner(&other).await.unwrap();
+        assert!(contract_signature.is_maker_contract());
+
+        // no limit order protocol on Tron to hash and sign against
+        let order = PreparedOrder {
+            src_chain_id: ChainId::Tron,
+            ..order
+        };
+        for err in [
+            order.eip712_signing_hash().unwrap_err(),
+            order.sign(&signer).await.unwrap_err(),
+            order.order.describe(ChainId::Tron).unwrap_err(),
+        ] {
+            assert!(matches!(err, crate::Error::UnsupportedChainId(728126428)));
+        }
+    }
+
+    #[test]
+    fn test_solana_destination() {
+        let (request, result) = quote_fixture();
+        let request = QuoteRequest {
+            dst_chain_id: ChainId::Solana,
+            dst_token_address: usdc(ChainId::Solana),
+            ..request
+        };
+        let receiver: MultichainAddress = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+            .parse()
+            .unwrap();
+
+        let order = PreparedOrder::from_quote(
+            &request,
+            &result,
+            CrossChainOrderParams {
+                dst_address: receiver,
+                ..order_params(PresetType::Fast, None)
+            },
+        )
+        .unwrap();
+        assert_eq!(order.to_v4().receiver, receiver.low_20_bytes());
+
+        let decoded = CrossChainOrder::from_data_and_extension(
+            &order.to_v4(),
+            order.order.inner.extension.build().encode(),
+        )
+        .unwrap();
+        let summary = decoded.describe(ChainId::Arbitrum).unwrap();
+        assert_eq!(summary.dst_chain_id, ChainId::Solana);
+        assert_eq!(summary.dst_token, usdc(ChainId::Solana));
+        assert_eq!(summary.receiver, receiver);
+    }
+
+    #[test]
+    fn test_native_source() {
+        let (request, result) = quote_fixture();
+        let native_request = QuoteRequest {
+            src_token_address: NATIVE_CURRENCY,
+            ..request.clone()
+        };
+
+        let err = PreparedOrder::from_quote(
+            &native_request,
+            &result,
+            order_params(PresetType::Fast, None),
+        )
+        .unwrap_err();
+        assert!(matches!(err, crate::Error::InternalErrorStr(_)));
+
+        let wrapped_request = native_request.with_wrapped_native_src();
+        assert_eq!(
+            wrapped_request.src_token_address,
+            get_wrapped_native_address(ChainId::Arbitrum)
+        );
+
+        let order = PreparedOrder::from_quote(
+            &wrapped_request,
+            &result,
+            order_params(PresetType::Fast, None),
+        )
+        .unwrap();
+        assert_eq!(
+            order.to_v4().makerAsset,
+            get_wrapped_native_address(ChainId::Arbitrum).as_raw()
+        );
+        assert!(!MakerTraits::new(order.to_v4().makerTraits).is_native_unwrap_enabled());
+    }
+}
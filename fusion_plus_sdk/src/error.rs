@@ -10,7 +10,21 @@ pub enum Error {
     UnsupportedChainIdStr(String),
     MultichainAddressDecodeFailed(String),
     NetworkNameNotRecognised(String),
+    InvalidTimeLocks(String),
+    InvalidMakerTraits(String),
+    InvalidTokenAmount(String),
+    InvalidSignature(String),
+    InvalidSecretHashes(String),
+    InvalidFee(String),
+    InvalidReceiver(String),
+    TokenNotFound(String),
+    Decode {
+        field: &'static str,
+        offset: usize,
+        reason: String,
+    },
     Reqwest(Box<reqwest::Error>),
+    Transport(Box<alloy::transports::TransportError>),
     SerdePathToError(Box<serde_path_to_error::Error<serde_json::Error>>),
 }
 
@@ -20,6 +34,8 @@ impl Display for Error {
     }
 }
 
+impl std::error::Error for Error {}
+
 impl From<String> for Error {
     fn from(err: String) -> Self {
         Error::InternalError(err)
@@ -37,3 +53,9 @@ impl From<reqwest::Error> for Error {
         Error::Reqwest(Box::new(err))
     }
 }
+
+impl From<alloy::transports::TransportError> for Error {
+    fn from(err: alloy::transports::TransportError) -> Self {
+        Error::Transport(Box::new(err))
+    }
+}
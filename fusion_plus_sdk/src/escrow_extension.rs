@@ -1,8 +1,10 @@
 use alloy::{
     dyn_abi::{DynSolType, DynSolValue},
-    primitives::{Address, Bytes, U256},
+    primitives::{B256, Bytes, FixedBytes, U256},
 };
 
+use num_enum::TryFromPrimitive;
+
 use crate::{
     chain_id::ChainId,
     constants::{NATIVE_CURRENCY, UINT_128_MAX},
@@ -18,7 +20,7 @@ use crate::{
     time_locks::TimeLocks,
 };
 
-const EXTRA_DATA_BYTES_LENGTH: usize = 160;
+pub const EXTRA_DATA_BYTES_LENGTH: usize = 160;
 
 pub struct EscrowParams {
     pub hash_lock: HashLock,
@@ -38,6 +40,9 @@ pub struct EscrowExtension {
     pub src_safety_deposit: U256,
     pub dst_safety_deposit: U256,
     pub time_locks: TimeLocks,
+    // High 12 bytes of a 32 byte destination receiver, the order receiver holds the rest.
+    // Stored as the custom data of the extension, zero for 20 byte receivers
+    pub dst_address_first_part: FixedBytes<12>,
 }
 
 impl EscrowExtension {
@@ -71,9 +76,29 @@ impl EscrowExtension {
             src_safety_deposit: escrow_params.src_safety_deposit,
             dst_safety_deposit: escrow_params.dst_safety_deposit,
             time_locks: escrow_params.timelocks,
+            dst_address_first_part: FixedBytes::ZERO,
         }
     }
 
+    pub fn with_dst_receiver(mut self, receiver: MultichainAddress) -> Self {
+        self.dst_address_first_part = receiver.first_part();
+        self
+    }
+
+    // Receiver on the destination chain, `order_receiver` is the receiver of the source order
+    pub fn dst_receiver(
+        &self,
+        order_receiver: MultichainAddress,
+    ) -> crate::Result<MultichainAddress> {
+        let word = [
+            self.dst_address_first_part.as_slice(),
+            order_receiver.low_20_bytes().as_slice(),
+        ]
+        .concat();
+
+        MultichainAddress::from_bytes32(self.dst_chain_id, B256::from_slice(&word))
+    }
+
     pub fn encode_extra_data(&self) -> Bytes {
         let dst_token = if self.dst_token == NATIVE_CURRENCY {
             MultichainAddress::ZERO
@@ -84,7 +109,8 @@ impl EscrowExtension {
         DynSolValue::Tuple(vec![
             DynSolValue::FixedBytes(self.hash_lock_info.value(), 32),
             DynSolValue::Uint(U256::from(self.dst_chain_id as u64), 256),
-            DynSolValue::Address(dst_token.as_raw()),
+            // a full word, Solana tokens use all 32 bytes
+            DynSolValue::FixedBytes(dst_token.as_bytes32(), 32),
             DynSolValue::Uint(
                 (self.src_safety_deposit << 128) | self.dst_safety_deposit,
                 256,
@@ -95,24 +121,43 @@ impl EscrowExtension {
         .into()
     }
 
-    pub fn decode_from(bytes: Bytes) -> Self {
-        let extension = Extension::decode_from(bytes);
+    pub fn decode_from(bytes: Bytes) -> crate::Result<Self> {
+        let extension = Extension::decode_from(bytes)?;
         EscrowExtension::from_extension(extension)
     }
 
-    pub fn from_extension(extension: Extension) -> Self {
+    pub fn from_extension(extension: Extension) -> crate::Result<Self> {
         let (base_post_interaction, extra_data) = {
             let post_interaction = extension.post_interaction.clone();
             let extra_data_start = post_interaction
                 .len()
                 .checked_sub(EXTRA_DATA_BYTES_LENGTH)
-                .expect("post interaction data too short for escrow extra data");
+                .ok_or_else(|| crate::Error::Decode {
+                    field: "post_interaction",
+                    offset: 0,
+                    reason: format!(
+                        "{} bytes is too short for escrow extra data",
+                        post_interaction.len()
+                    ),
+                })?;
             let (base, tail) = post_interaction.split_at(extra_data_start);
             (base.to_vec().into(), tail.to_vec().into())
         };
 
+        let dst_address_first_part = match extension.custom_data.len() {
+            0 => FixedBytes::ZERO,
+            12 => FixedBytes::from_slice(&extension.custom_data),
+            len => {
+                return Err(crate::Error::Decode {
+                    field: "custom_data",
+                    offset: 0,
+                    reason: format!("expected 12 bytes of destination address, got {len}"),
+                });
+            }
+        };
+
         let base_extension = extension.with_post_interaction(base_post_interaction);
-        let fusion_ext = FusionExtension::from_extension(base_extension);
+        let fusion_ext = FusionExtension::from_extension(base_extension)?;
 
         let (
             hash_lock,
@@ -121,9 +166,9 @@ impl EscrowExtension {
             src_safety_deposit,
             dst_safety_deposit,
             time_locks,
-        ) = EscrowExtension::decode_extra_data(extra_data);
+        ) = EscrowExtension::decode_extra_data(extra_data)?;
 
-        EscrowExtension {
+        Ok(EscrowExtension {
             fusion_extension: fusion_ext,
             hash_lock_info: hash_lock,
             dst_chain_id,
@@ -131,62 +176,97 @@ impl EscrowExtension {
             src_safety_deposit,
             dst_safety_deposit,
             time_locks,
-        }
+            dst_address_first_part,
+        })
     }
 
+    #[allow(clippy::type_complexity)]
     pub fn decode_extra_data(
         bytes: Bytes,
-    ) -> (HashLock, ChainId, MultichainAddress, U256, U256, TimeLocks) {
+    ) -> crate::Result<(HashLock, ChainId, MultichainAddress, U256, U256, TimeLocks)> {
         let schema = DynSolType::Tuple(vec![
             DynSolType::FixedBytes(32), // hash_lock
             DynSolType::Uint(256),      // dst_chain_id
-            DynSolType::Address,        // dst_token
+            DynSolType::FixedBytes(32), // dst_token
             DynSolType::Uint(256),      // safety_deposit (128+128)
             DynSolType::Uint(256),      // time_locks
         ]);
 
-        let DynSolValue::Tuple(values) = schema
-            .abi_decode(&bytes)
-            .expect("Invalid extra data encoding")
-        else {
-            panic!("Expected tuple decoding");
+        // the tuple only holds static types, each value occupies one 32 byte word
+        let field_error = |index: usize, field: &'static str, reason: &str| crate::Error::Decode {
+            field,
+            offset: index * 32,
+            reason: reason.to_string(),
         };
 
-        let hash_lock = values[0].as_word().expect("Invalid hash_lock type");
+        let values = match schema.abi_decode(&bytes) {
+            Ok(DynSolValue::Tuple(values)) if values.len() == 5 => values,
+            Ok(_) => return Err(field_error(0, "extra_data", "expected 5 element tuple")),
+            Err(e) => return Err(field_error(0, "extra_data", &e.to_string())),
+        };
+
+        let hash_lock = values[0]
+            .as_word()
+            .ok_or_else(|| field_error(0, "hash_lock", "expected bytes32"))?;
 
-        let (dst_chain_id, _) = values[1].as_uint().expect("Invalid dst_chain_id type");
-        let dst_chain_id = ChainId::from_u32(dst_chain_id.to::<u32>());
+        let (dst_chain_id, _) = values[1]
+            .as_uint()
+            .ok_or_else(|| field_error(1, "dst_chain_id", "expected uint256"))?;
+        let dst_chain_id = u32::try_from(dst_chain_id)
+            .ok()
+            .and_then(|id| ChainId::try_from_primitive(id).ok())
+            .ok_or_else(|| field_error(1, "dst_chain_id", "unsupported chain id"))?;
 
-        let dst_token = match values[2].as_address().expect("Invalid dst_token type") {
-            Address::ZERO => NATIVE_CURRENCY,
-            addr => MultichainAddress::from_raw(addr),
+        let dst_token = match values[2]
+            .as_word()
+            .ok_or_else(|| field_error(2, "dst_token", "expected bytes32"))?
+        {
+            B256::ZERO => NATIVE_CURRENCY,
+            word => MultichainAddress::from_bytes32(dst_chain_id, word)
+                .map_err(|e| field_error(2, "dst_token", &e.to_string()))?,
         };
 
         let (src_safety_deposit, dst_safety_deposit) = {
-            let (safety, _) = values[3].as_uint().expect("Invalid safety deposit type");
+            let (safety, _) = values[3]
+                .as_uint()
+                .ok_or_else(|| field_error(3, "safety_deposit", "expected uint256"))?;
             let src = safety >> 128;
             let dst = safety & UINT_128_MAX;
             (src, dst)
         };
 
-        let (time_locks, _) = values[4].as_uint().expect("Invalid time locks type");
+        let (time_locks, _) = values[4]
+            .as_uint()
+            .ok_or_else(|| field_error(4, "time_locks", "expected uint256"))?;
 
-        (
+        Ok((
             HashLock::new(hash_lock),
             dst_chain_id,
             dst_token,
             src_safety_deposit,
             dst_safety_deposit,
             TimeLocks::from_u256(time_locks),
-        )
+        ))
     }
 }
 
 impl ExtensionBuildable for EscrowExtension {
     fn build(&self) -> Extension {
-        self.fusion_extension
+        let mut extension = self
+            .fusion_extension
             .build()
-            .append_post_interaction(self.encode_extra_data())
+            .append_post_interaction(self.encode_extra_data());
+        if self.dst_address_first_part != FixedBytes::ZERO {
+            extension.custom_data = self.dst_address_first_part.into();
+        }
+
+        extension
+    }
+
+    // The escrow factory derives the maker of the dst escrow from the order receiver, it
+    // has to stay the receiver on the destination chain whatever the fees
+    fn receives_taker_asset(&self) -> bool {
+        false
     }
 }
 
@@ -200,14 +280,15 @@ mod tests {
             fusion_extension: FusionExtension::default(),
             hash_lock_info: HashLock::new([3; 32].into()),
             dst_chain_id: ChainId::Ethereum,
-            dst_token: MultichainAddress::from_raw(Address::ZERO.create(1)),
+            dst_token: MultichainAddress::from_raw(alloy::primitives::Address::ZERO.create(1)),
             src_safety_deposit: U256::from(1000),
             dst_safety_deposit: U256::from(2000),
             time_locks: TimeLocks::new(36, 372, 528, 648, 60, 336, 456, Some(80)),
+            dst_address_first_part: FixedBytes::ZERO,
         };
 
         let encoded = ext.encode_extra_data();
-        let decoded = EscrowExtension::decode_extra_data(encoded);
+        let decoded = EscrowExtension::decode_extra_data(encoded).unwrap();
 
         assert_eq!(decoded.0, ext.hash_lock_info);
         assert_eq!(decoded.1, ext.dst_chain_id);
@@ -216,4 +297,102 @@ mod tests {
         assert_eq!(decoded.4, ext.dst_safety_deposit);
         assert_eq!(decoded.5, ext.time_locks);
     }
+
+    #[test]
+    fn test_solana_destination() {
+        let receiver: MultichainAddress = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+            .parse()
+            .unwrap();
+        let ext = EscrowExtension {
+            fusion_extension: FusionExtension::default(),
+            hash_lock_info: HashLock::new([3; 32].into()),
+            dst_chain_id: ChainId::Solana,
+            dst_token: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+                .parse()
+                .unwrap(),
+            src_safety_deposit: U256::from(1000),
+            dst_safety_deposit: U256::from(2000),
+            time_locks: TimeLocks::new(36, 372, 528, 648, 60, 336, 456, Some(80)),
+            dst_address_first_part: FixedBytes::ZERO,
+        }
+        .with_dst_receiver(receiver);
+
+        let encoded = ext.encode_extra_data();
+        assert_eq!(encoded.len(), EXTRA_DATA_BYTES_LENGTH);
+        assert_eq!(encoded[64..96], ext.dst_token.as_bytes32()[..]);
+
+        let decoded = EscrowExtension::decode_extra_data(encoded).unwrap();
+        assert_eq!(decoded.2, ext.dst_token);
+
+        let extension = ext.build();
+        assert_eq!(extension.custom_data[..], receiver.first_part()[..]);
+        assert_eq!(
+            ext.dst_receiver(MultichainAddress::from_raw(receiver.low_20_bytes()))
+                .unwrap(),
+            receiver
+        );
+
+        // a 32 byte token can not be decoded for an EVM destination
+        let mut encoded = ext.encode_extra_data().to_vec();
+        encoded[62] = 0;
+        encoded[63] = 1;
+        let err = EscrowExtension::decode_extra_data(encoded.into()).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::Decode {
+                field: "dst_token",
+                offset: 64,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_decode_malformed() {
+        let ext = EscrowExtension {
+            fusion_extension: FusionExtension::default(),
+            hash_lock_info: HashLock::new([3; 32].into()),
+            dst_chain_id: ChainId::Ethereum,
+            dst_token: MultichainAddress::from_raw(alloy::primitives::Address::ZERO.create(1)),
+            src_safety_deposit: U256::from(1000),
+            dst_safety_deposit: U256::from(2000),
+            time_locks: TimeLocks::new(36, 372, 528, 648, 60, 336, 456, Some(80)),
+            dst_address_first_part: FixedBytes::ZERO,
+        };
+
+        let mut encoded = ext.encode_extra_data().to_vec();
+        encoded[63] = 0xff; // dst_chain_id
+        let err = EscrowExtension::decode_extra_data(encoded.clone().into()).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::Decode {
+                field: "dst_chain_id",
+                offset: 32,
+                ..
+            }
+        ));
+
+        encoded.truncate(100);
+        let err = EscrowExtension::decode_extra_data(encoded.into()).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::Decode {
+                field: "extra_data",
+                ..
+            }
+        ));
+
+        let extension = Extension {
+            post_interaction: vec![0u8; 100].into(),
+            ..Default::default()
+        };
+        let err = EscrowExtension::from_extension(extension).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::Decode {
+                field: "post_interaction",
+                ..
+            }
+        ));
+    }
 }
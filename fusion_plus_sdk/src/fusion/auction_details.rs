@@ -51,24 +51,24 @@ impl AuctionDetails {
         result.into()
     }
 
-    pub fn decode_from(bytes: Bytes) -> Self {
-        let mut iter = BytesIter::new(bytes);
+    pub fn decode_from(bytes: Bytes) -> crate::Result<Self> {
+        let mut iter = BytesIter::new(bytes, "auction_details");
 
-        let gas_bump_estimate = iter.next_uint24(Side::Front).to::<u64>();
-        let gas_price_estimate = iter.next_uint32(Side::Front).to::<u64>();
-        let start_time = iter.next_uint32(Side::Front).to::<u64>();
-        let duration = iter.next_uint24(Side::Front).to::<u64>();
-        let initial_rate_bump = iter.next_uint24(Side::Front).to::<u64>();
+        let gas_bump_estimate = iter.next_uint24(Side::Front)?.to::<u64>();
+        let gas_price_estimate = iter.next_uint32(Side::Front)?.to::<u64>();
+        let start_time = iter.next_uint32(Side::Front)?.to::<u64>();
+        let duration = iter.next_uint24(Side::Front)?.to::<u64>();
+        let initial_rate_bump = iter.next_uint24(Side::Front)?.to::<u64>();
 
         let mut points = vec![];
         while !iter.is_empty() {
-            let coefficient = iter.next_uint24(Side::Front).to::<u64>();
-            let delay = iter.next_uint16(Side::Front).to::<u64>();
+            let coefficient = iter.next_uint24(Side::Front)?.to::<u64>();
+            let delay = iter.next_uint16(Side::Front)?.to::<u64>();
 
             points.push(AuctionPoint { coefficient, delay });
         }
 
-        AuctionDetails::new(
+        Ok(AuctionDetails::new(
             start_time,
             duration,
             initial_rate_bump,
@@ -77,12 +77,12 @@ impl AuctionDetails {
                 gas_bump_estimate,
                 gas_price_estimate: U256::from(gas_price_estimate),
             },
-        )
+        ))
     }
 
-    pub fn from_extension(extension: &Extension) -> Self {
-        let mut iter = BytesIter::new(extension.making_amount_data.clone());
-        let _ = iter.next_address(Side::Front);
+    pub fn from_extension(extension: &Extension) -> crate::Result<Self> {
+        let mut iter = BytesIter::new(extension.making_amount_data.clone(), "making_amount_data");
+        let _ = iter.next_address(Side::Front)?;
         Self::decode_from(iter.rest())
     }
 }
@@ -149,7 +149,7 @@ mod tests {
         );
 
         let encoded = details.encode();
-        let decoded = AuctionDetails::decode_from(encoded.clone());
+        let decoded = AuctionDetails::decode_from(encoded.clone()).unwrap();
 
         assert_eq!(details, decoded);
     }
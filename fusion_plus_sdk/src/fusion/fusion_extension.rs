@@ -36,34 +36,44 @@ impl FusionExtension {
         }
     }
 
-    pub fn from_extension(extension: Extension) -> Self {
-        let settlement_contract_1 = BytesIter::first_address(extension.making_amount_data.clone());
-        let settlement_contract_2 = BytesIter::first_address(extension.taking_amount_data.clone());
-        let settlement_extension_3 = BytesIter::first_address(extension.post_interaction.clone());
+    pub fn from_extension(extension: Extension) -> crate::Result<Self> {
+        let settlement_contract_1 =
+            BytesIter::first_address(extension.making_amount_data.clone(), "making_amount_data")?;
+        let settlement_contract_2 =
+            BytesIter::first_address(extension.taking_amount_data.clone(), "taking_amount_data")?;
+        let settlement_extension_3 =
+            BytesIter::first_address(extension.post_interaction.clone(), "post_interaction")?;
 
-        assert!(
-            settlement_contract_1 == settlement_contract_2
-                && settlement_contract_1 == settlement_extension_3,
-            "Invalid extension, all calls should be to the same address"
-        );
+        if settlement_contract_1 != settlement_contract_2
+            || settlement_contract_1 != settlement_extension_3
+        {
+            return Err(crate::Error::Decode {
+                field: "settlement_extension_contract",
+                offset: 0,
+                reason: "invalid extension, all calls should be to the same address".to_string(),
+            });
+        }
 
         // TODO this uses making_amount_data only. There seems to be no place that uses taking_amount_data
-        let auction_details = AuctionDetails::from_extension(&extension);
+        let auction_details = AuctionDetails::from_extension(&extension)?;
 
-        let post_interaction_data = SettlementPostInteractionData::from_extension(&extension);
+        let post_interaction_data = SettlementPostInteractionData::from_extension(&extension)?;
 
         let maker_permit = if extension.maker_permit.is_empty() {
             None
         } else {
-            Some(Interaction::decode_from(extension.maker_permit))
+            Some(Interaction::decode_from(
+                extension.maker_permit,
+                "maker_permit",
+            )?)
         };
 
-        Self {
+        Ok(Self {
             settlement_extension_contract: MultichainAddress::from_raw(settlement_contract_1),
             auction_details,
             post_interaction_data,
             maker_permit,
-        }
+        })
     }
 }
 
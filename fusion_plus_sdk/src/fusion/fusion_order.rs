@@ -1,4 +1,5 @@
 use alloy::primitives::{B256, Bytes, U256};
+use serde::Serialize;
 
 use crate::{
     chain_id::ChainId,
@@ -8,46 +9,69 @@ use crate::{
         source_track::inject_track_code,
     },
     limit::{
-        extension_builder::ExtensionBuildable, interaction::Interaction, limit_order::LimitOrder,
-        maker_traits::MakerTraits, order_info::OrderInfoData,
+        epoch_manager::SeriesEpoch, extension_builder::ExtensionBuildable,
+        interaction::Interaction, limit_order::LimitOrder, maker_traits::MakerTraits,
+        order_info::OrderInfoData,
     },
     multichain_address::MultichainAddress,
-    utils::bps::Bps,
+    utils::bps::{Bps, add_ratio_to_amount},
 };
 
-// https://github.com/1inch/fusion-sdk/blob/6d40f680a2f1cd0148c314d4c8608a004fffdc09/src/fusion-order/surplus-params.ts#L5
-#[allow(dead_code)]
-pub struct SurplusParams {
-    estimated_taker_amount: U256,
-    protocol_fee: Bps,
-}
-
-#[allow(dead_code)]
-#[derive(Clone, Debug)]
+// Charged from the resolver's fee bank on fill, does not change the amounts of the order
+#[derive(Clone, Debug, Default)]
 pub struct ResolverFee {
-    receiver: MultichainAddress,
-    fee: Bps,
+    pub bank_fee: u64,
 }
 
-#[allow(dead_code)]
+// Charged from the taker on top of the auction amount and sent to `receiver` by the
+// settlement extension, `ratio` is in 1/100000 of the taking amount
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct IntegratorFee {
     pub receiver: MultichainAddress,
     pub ratio: u64,
 }
 
-#[allow(dead_code)]
-#[derive(Clone, Debug)]
+// The settlement extension reads the fee ratio (bps * 10) as a u16
+pub const MAX_INTEGRATOR_FEE_BPS: u64 = u16::MAX as u64 / 10;
+
+impl IntegratorFee {
+    pub fn from_bps(receiver: MultichainAddress, bps: u64) -> crate::Result<Self> {
+        if bps > MAX_INTEGRATOR_FEE_BPS {
+            return Err(crate::Error::InvalidFee(format!(
+                "{bps} bps exceeds the maximum integrator fee of {MAX_INTEGRATOR_FEE_BPS} bps"
+            )));
+        }
+        if receiver.try_as_raw().is_err() {
+            return Err(crate::Error::InvalidFee(format!(
+                "integrator fee receiver {receiver} is not a 20 byte address"
+            )));
+        }
+
+        Ok(Self {
+            receiver,
+            ratio: Bps::to_ratio_format(Some(bps)),
+        })
+    }
+
+    pub fn fee_amount(&self, taking_amount: U256) -> U256 {
+        add_ratio_to_amount(taking_amount, self.ratio) - taking_amount
+    }
+}
+
+// Fees encoded by `SettlementPostInteractionData`. Its format has no fields for a
+// protocol fee or surplus sharing, so orders can not charge them
+#[derive(Clone, Debug, Default)]
 pub struct Fees {
-    resolver: ResolverFee,
-    integrator: IntegratorFee,
+    pub integrator: Option<IntegratorFee>,
+    pub resolver: Option<ResolverFee>,
 }
 
 #[derive(Clone, Debug)]
 pub struct FusionOrderExtra {
     pub unwrap_weth: Option<bool>,
     pub nonce: Option<u64>,
+    pub epoch: Option<SeriesEpoch>,
     pub permit: Option<Bytes>,
     pub allow_partial_fills: Option<bool>,
     pub allow_multiple_fills: Option<bool>,
@@ -71,7 +95,7 @@ impl FusionOrder<FusionExtension> {
         auction_details: AuctionDetails,
         post_interaction_data: SettlementPostInteractionData,
         extra: Option<FusionOrderExtra>,
-    ) -> Self {
+    ) -> crate::Result<Self> {
         let maker_permit = extra
             .as_ref()
             .and_then(|extra| extra.permit.as_ref())
@@ -103,10 +127,11 @@ impl<E: ExtensionBuildable> FusionOrder<E> {
         post_interaction_data: SettlementPostInteractionData,
         extra: Option<FusionOrderExtra>,
         extension: E,
-    ) -> Self {
+    ) -> crate::Result<Self> {
         let extra_default = FusionOrderExtra {
             unwrap_weth: Some(false),
             nonce: None,
+            epoch: None,
             permit: None,
             allow_partial_fills: Some(true),
             allow_multiple_fills: Some(true),
@@ -137,19 +162,18 @@ impl<E: ExtensionBuildable> FusionOrder<E> {
             .unwrap();
 
         let deadline =
-            auction_details.start_time * auction_details.duration + order_expiration_delay;
+            auction_details.start_time + auction_details.duration + order_expiration_delay;
 
         let mut maker_traits = MakerTraits::default()
-            .with_expiration(deadline)
+            .try_with_expiration(deadline)?
             .set_partial_fills(allow_partial_fills)
             .set_multiple_fills(allow_multiple_fills)
             .enable_post_interaction();
 
-        if maker_traits.is_bit_invalidator_mode() {
-            assert!(
-                extra.nonce.is_some(),
-                "Nonce required, when partial fill or multiple fill disallowed"
-            )
+        if maker_traits.is_bit_invalidator_mode() && extra.nonce.is_none() {
+            return Err(crate::Error::InvalidMakerTraits(
+                "Nonce required, when partial fill or multiple fill disallowed".to_string(),
+            ));
         }
 
         if unwrap_weth {
@@ -161,14 +185,19 @@ impl<E: ExtensionBuildable> FusionOrder<E> {
         }
 
         if let Some(nonce) = extra.nonce {
-            maker_traits = maker_traits.with_nonce(nonce);
+            maker_traits = maker_traits.try_with_nonce(nonce)?;
+        }
+
+        if let Some(SeriesEpoch { series, epoch }) = extra.epoch {
+            maker_traits = maker_traits.try_with_epoch(series, epoch)?;
         }
 
-        let receiver = if post_interaction_data
-            .integrator_fee
-            .map(|integrator_fee| integrator_fee.ratio)
-            .unwrap_or_default()
-            > 0
+        let receiver = if extension.receives_taker_asset()
+            && post_interaction_data
+                .integrator_fee
+                .map(|integrator_fee| integrator_fee.ratio)
+                .unwrap_or_default()
+                > 0
         {
             settlement_extension_contract
         } else {
@@ -192,14 +221,14 @@ impl<E: ExtensionBuildable> FusionOrder<E> {
             Some(built_extension),
         );
 
-        Self {
+        Ok(Self {
             settlement_extension_contract,
             extension,
             inner,
-        }
+        })
     }
 
-    pub fn get_order_hash(&self, chain_id: ChainId) -> B256 {
+    pub fn get_order_hash(&self, chain_id: ChainId) -> crate::Result<B256> {
         self.inner.get_order_hash(chain_id)
     }
 }
@@ -66,12 +66,18 @@ impl SettlementPostInteractionData {
             })
             .collect();
 
+        // without an integrator fee the order receiver is the receiver, see `encode`
+        let has_integrator_fee = data
+            .integrator_fee
+            .as_ref()
+            .is_some_and(|integrator_fee| integrator_fee.ratio > 0);
+
         SettlementPostInteractionData {
             whitelist,
             integrator_fee: data.integrator_fee,
             bank_fee: data.bank_fee,
             resolving_start_time: data.resolving_start_time,
-            custom_receiver: data.custom_receiver,
+            custom_receiver: data.custom_receiver.filter(|_| has_integrator_fee),
         }
     }
 
@@ -79,23 +85,33 @@ impl SettlementPostInteractionData {
         let mut flags = U256::ZERO; // u8
         let mut bytes = BytesBuilder::default();
 
-        if let Some(bank_fee) = self.bank_fee {
-            if bank_fee > 0 {
-                flags.set_bit(0, true);
-                bytes.push_uint32(bank_fee as u32);
-            }
+        if let Some(bank_fee) = self.bank_fee
+            && bank_fee > 0
+        {
+            flags.set_bit(0, true);
+            bytes.push_uint32(
+                u32::try_from(bank_fee)
+                    .expect("resolver fee over u32, see PreparedOrder::from_quote"),
+            );
         }
 
-        if let Some(integrator_fee) = &self.integrator_fee {
-            if integrator_fee.ratio > 0 {
-                flags.set_bit(1, true);
-                bytes.push_uint16(integrator_fee.ratio as u16);
-                bytes.push_address(integrator_fee.receiver.as_raw());
-
-                if let Some(custom_receiver) = self.custom_receiver {
-                    flags.set_bit(2, true);
-                    bytes.push_address(custom_receiver.as_raw());
-                }
+        if let Some(integrator_fee) = &self.integrator_fee
+            && integrator_fee.ratio > 0
+        {
+            flags.set_bit(1, true);
+            bytes.push_uint16(
+                u16::try_from(integrator_fee.ratio)
+                    .expect("integrator fee ratio over u16, see IntegratorFee::from_bps"),
+            );
+            // `IntegratorFee::from_bps` rejects receivers which do not fit in 20 bytes
+            bytes.push_address(integrator_fee.receiver.low_20_bytes());
+
+            // the settlement extension reads the custom receiver only after an integrator
+            // fee, orders without a fee have their receiver in the order itself. Like the
+            // order receiver it holds the low 20 bytes of a 32 byte dst receiver
+            if let Some(custom_receiver) = self.custom_receiver {
+                flags.set_bit(2, true);
+                bytes.push_address(custom_receiver.low_20_bytes());
             }
         }
 
@@ -113,37 +129,46 @@ impl SettlementPostInteractionData {
         bytes.into_value()
     }
 
-    pub fn decode_from(bytes: Bytes) -> Self {
-        let mut iter = BytesIter::new(bytes);
-        let flags = iter.next_uint8(Side::Back);
+    pub fn decode_from(bytes: Bytes) -> crate::Result<Self> {
+        let mut iter = BytesIter::new(bytes, "post_interaction_data");
+        let flags = iter.next_uint8(Side::Back)?;
 
         let mut bank_fee = None;
         let mut integrator_fee = None;
         let mut custom_receiver = None;
 
         if flags.bit(0) {
-            bank_fee = Some(iter.next_uint32(Side::Front).to::<u64>());
+            bank_fee = Some(iter.next_uint32(Side::Front)?.to::<u64>());
         }
 
         if flags.bit(1) {
-            let ratio = iter.next_uint16(Side::Front).to::<u64>();
-            let receiver = MultichainAddress::from_raw(iter.next_address(Side::Front));
+            let ratio = iter.next_uint16(Side::Front)?.to::<u64>();
+            let receiver = MultichainAddress::from_raw(iter.next_address(Side::Front)?);
             integrator_fee = Some(IntegratorFee { ratio, receiver });
 
             if flags.bit(2) {
-                custom_receiver = Some(MultichainAddress::from_raw(iter.next_address(Side::Front)));
+                custom_receiver =
+                    Some(MultichainAddress::from_raw(iter.next_address(Side::Front)?));
             }
         }
 
-        let resolving_start_time = iter.next_uint32(Side::Front).to::<u64>();
+        let resolving_start_time = iter.next_uint32(Side::Front)?.to::<u64>();
+
+        let whitelist_count = BitMask::new(3, Some(8)).get_from(flags).to::<usize>();
+        if iter.len() != whitelist_count * 12 {
+            return Err(iter.error(format!(
+                "expected {whitelist_count} whitelist items, have {} bytes",
+                iter.len()
+            )));
+        }
 
         let mut whitelist = vec![];
         while !iter.is_empty() {
-            let address_half_bytes = iter.next_bytes(10, Side::Front);
+            let address_half_bytes = iter.next_bytes(10, Side::Front)?;
             let mut addess_half = [0u8; 10];
             addess_half.copy_from_slice(&address_half_bytes);
 
-            let delay = iter.next_uint16(Side::Front).to::<u64>();
+            let delay = iter.next_uint16(Side::Front)?.to::<u64>();
 
             whitelist.push(WhitelistItem {
                 address_half: addess_half.into(),
@@ -151,18 +176,18 @@ impl SettlementPostInteractionData {
             });
         }
 
-        Self {
+        Ok(Self {
             whitelist,
             integrator_fee,
             bank_fee,
             resolving_start_time,
             custom_receiver,
-        }
+        })
     }
 
-    pub fn from_extension(extension: &Extension) -> Self {
-        let mut iter = BytesIter::new(extension.post_interaction.clone());
-        let _ = iter.next_address(Side::Front);
+    pub fn from_extension(extension: &Extension) -> crate::Result<Self> {
+        let mut iter = BytesIter::new(extension.post_interaction.clone(), "post_interaction");
+        let _ = iter.next_address(Side::Front)?;
 
         Self::decode_from(iter.rest())
     }
@@ -171,6 +196,7 @@ impl SettlementPostInteractionData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fusion::fusion_order::MAX_INTEGRATOR_FEE_BPS;
 
     #[test]
     fn encode_decode() {
@@ -205,8 +231,91 @@ mod tests {
         };
 
         let encoded = post_interaction.encode();
-        let decoded = SettlementPostInteractionData::decode_from(encoded.clone());
+        let decoded = SettlementPostInteractionData::decode_from(encoded.clone()).unwrap();
 
         assert_eq!(post_interaction, decoded);
     }
+
+    #[test]
+    fn integrator_fee_bounds() {
+        let receiver = MultichainAddress::ZERO;
+
+        let fee = IntegratorFee::from_bps(receiver, 50).unwrap();
+        assert_eq!(fee.ratio, 500);
+        assert_eq!(fee.fee_amount(U256::from(10_000_000)), U256::from(50_000));
+
+        let fee = IntegratorFee::from_bps(receiver, MAX_INTEGRATOR_FEE_BPS).unwrap();
+        assert_eq!(fee.ratio, 65_530);
+        let data = SettlementPostInteractionData {
+            integrator_fee: Some(fee),
+            ..Default::default()
+        };
+        assert_eq!(
+            SettlementPostInteractionData::decode_from(data.encode())
+                .unwrap()
+                .integrator_fee
+                .unwrap()
+                .ratio,
+            65_530
+        );
+
+        for bps in [MAX_INTEGRATOR_FEE_BPS + 1, 10_000] {
+            assert!(matches!(
+                IntegratorFee::from_bps(receiver, bps),
+                Err(crate::Error::InvalidFee(_))
+            ));
+        }
+
+        let solana: MultichainAddress = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+            .parse()
+            .unwrap();
+        assert!(matches!(
+            IntegratorFee::from_bps(solana, 50),
+            Err(crate::Error::InvalidFee(_))
+        ));
+    }
+
+    #[test]
+    fn solana_custom_receiver() {
+        let receiver: MultichainAddress = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+            .parse()
+            .unwrap();
+        let data = SettlementPostInteractionData {
+            integrator_fee: Some(IntegratorFee::from_bps(MultichainAddress::ZERO, 50).unwrap()),
+            custom_receiver: Some(receiver),
+            ..Default::default()
+        };
+
+        let decoded = SettlementPostInteractionData::decode_from(data.encode()).unwrap();
+        assert_eq!(
+            decoded.custom_receiver.unwrap().as_raw(),
+            receiver.low_20_bytes()
+        );
+    }
+
+    #[test]
+    fn decode_malformed() {
+        assert!(matches!(
+            SettlementPostInteractionData::decode_from(Bytes::new()).unwrap_err(),
+            crate::Error::Decode {
+                field: "post_interaction_data",
+                offset: 0,
+                ..
+            }
+        ));
+
+        // one whitelist item announced in flags but the item is truncated
+        let err = SettlementPostInteractionData::decode_from(
+            "0x6887ccd3cb4fa6eb00f6ea887a4a08".parse().unwrap(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::Decode {
+                field: "post_interaction_data",
+                offset: 4,
+                ..
+            }
+        ));
+    }
 }
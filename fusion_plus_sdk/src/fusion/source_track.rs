@@ -46,3 +46,7 @@ pub fn inject_track_code(salt: U256, source: Option<String>) -> U256 {
 
     track_code_mask().set_at(salt, track)
 }
+
+pub fn extract_track_code(salt: U256) -> U256 {
+    track_code_mask().get_from(salt)
+}
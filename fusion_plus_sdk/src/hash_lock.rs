@@ -1,8 +1,4 @@
-use alloy::{
-    dyn_abi::DynSolValue,
-    primitives::{B256, keccak256},
-};
-use alloy_merkle_tree::standard_binary_tree::StandardMerkleTree;
+use alloy::primitives::{B256, keccak256};
 use serde::Serialize;
 
 #[cfg_attr(test, derive(PartialEq))]
@@ -29,6 +25,12 @@ impl HashLock {
         self.hash
     }
 
+    // Only meaningful for multiple fills, where the top 16 bits store the number of parts,
+    // one less than the number of secrets
+    pub fn parts_count(&self) -> u64 {
+        u16::from_be_bytes([self.hash.0[0], self.hash.0[1]]) as u64
+    }
+
     pub fn hash_secret(secret: &B256) -> B256 {
         keccak256(secret)
     }
@@ -38,29 +40,193 @@ impl HashLock {
         HashLock::new(hash)
     }
 
-    pub fn for_multiple_fills(secret: Vec<B256>) -> crate::Result<Self> {
-        if secret.len() <= 2 {
+    // https://github.com/1inch/cross-chain-sdk/blob/25ac3927c706a43e85f2f08cc9d9a3bdf156e1e9/src/cross-chain-order/hash-lock/hash-lock.ts
+    pub fn get_merkle_leaves(secrets: &[B256]) -> Vec<B256> {
+        Self::get_merkle_leaves_by_secret_hashes(
+            &secrets.iter().map(Self::hash_secret).collect::<Vec<_>>(),
+        )
+    }
+
+    pub fn get_merkle_leaves_by_secret_hashes(secret_hashes: &[B256]) -> Vec<B256> {
+        secret_hashes
+            .iter()
+            .enumerate()
+            .map(|(idx, secret_hash)| {
+                let mut encoded = [0u8; 40];
+                encoded[0..8].copy_from_slice(&(idx as u64).to_be_bytes());
+                encoded[8..40].copy_from_slice(secret_hash.as_ref());
+                keccak256(encoded)
+            })
+            .collect()
+    }
+
+    pub fn from_secrets(secrets: &[B256]) -> crate::Result<Self> {
+        Self::from_secret_hashes(&secrets.iter().map(Self::hash_secret).collect::<Vec<_>>())
+    }
+
+    pub fn from_secret_hashes(secret_hashes: &[B256]) -> crate::Result<Self> {
+        match secret_hashes {
+            [] => Err(crate::Error::InternalErrorStr(
+                "at least one secret required",
+            )),
+            [secret_hash] => Ok(HashLock::new(*secret_hash)),
+            secret_hashes => {
+                Self::for_multiple_fills(Self::get_merkle_leaves_by_secret_hashes(secret_hashes))
+            }
+        }
+    }
+
+    pub fn for_multiple_fills(leaves: Vec<B256>) -> crate::Result<Self> {
+        if leaves.len() <= 2 {
             return Err(crate::Error::InternalErrorStr(
                 "leaves array must be greater than 2. Or use HashLock.forSingleFill",
             ));
         }
 
-        let root = StandardMerkleTree::of(
-            // TODO PR to improve this code https://github.com/alloy-rs/core/pull/983
-            &secret
-                .iter()
-                .map(|s| DynSolValue::FixedBytes(*s, 32))
-                .collect::<Vec<DynSolValue>>(),
-        )
-        .root();
-
-        let mut root_with_count = root;
+        let (tree, _) = merkle_tree(&leaves);
+        let mut root_with_count = tree[0];
 
         // setMask https://github.com/1inch/cross-chain-sdk/blob/25ac3927c706a43e85f2f08cc9d9a3bdf156e1e9/src/cross-chain-order/hash-lock/hash-lock.ts#L68
-        let length = secret.len() as u16;
-        root_with_count.0[0] = (length >> 8) as u8;
-        root_with_count.0[1] = (length & 0xFF) as u8;
+        // the escrow factory reads the number of parts, the last secret completes the fill
+        let parts = (leaves.len() - 1) as u16;
+        root_with_count.0[..2].copy_from_slice(&parts.to_be_bytes());
 
         Ok(HashLock::new(root_with_count))
     }
+
+    // Proof of the leaf `idx` for `MerkleStorageInvalidator.takerInteraction`
+    pub fn get_proof(leaves: &[B256], idx: usize) -> crate::Result<Vec<B256>> {
+        if idx >= leaves.len() {
+            return Err(crate::Error::InternalErrorStr(
+                "merkle leaf index out of range",
+            ));
+        }
+
+        let (tree, positions) = merkle_tree(leaves);
+        let mut proof = vec![];
+        let mut node = positions[idx];
+        while node > 0 {
+            let sibling = if node % 2 == 0 { node - 1 } else { node + 1 };
+            proof.push(tree[sibling]);
+            node = (node - 1) / 2;
+        }
+
+        Ok(proof)
+    }
+}
+
+// Tree of OpenZeppelin's `SimpleMerkleTree.of(leaves)` as used by the 1inch SDKs: leaves are
+// taken as is, sorted, and stored in reverse at the end of the node array. Returns the nodes,
+// root first, and the node index of every leaf
+fn merkle_tree(leaves: &[B256]) -> (Vec<B256>, Vec<usize>) {
+    let mut sorted: Vec<(usize, B256)> = leaves.iter().copied().enumerate().collect();
+    sorted.sort_by_key(|(_, leaf)| *leaf);
+
+    let len = 2 * leaves.len() - 1;
+    let mut tree = vec![B256::ZERO; len];
+    let mut positions = vec![0; leaves.len()];
+    for (i, (idx, leaf)) in sorted.into_iter().enumerate() {
+        tree[len - 1 - i] = leaf;
+        positions[idx] = len - 1 - i;
+    }
+    for node in (0..len - leaves.len()).rev() {
+        tree[node] = hash_pair(tree[2 * node + 1], tree[2 * node + 2]);
+    }
+
+    (tree, positions)
+}
+
+fn hash_pair(a: B256, b: B256) -> B256 {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    keccak256([left.as_slice(), right.as_slice()].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        primitives::{Address, U256, b256},
+        sol_types::SolValue,
+    };
+
+    use super::*;
+
+    // MerkleProof.processProof of OpenZeppelin, as the escrow factory verifies the proof
+    fn process_proof(leaf: B256, proof: &[B256]) -> B256 {
+        proof
+            .iter()
+            .fold(leaf, |node, sibling| hash_pair(node, *sibling))
+    }
+
+    #[test]
+    fn test_multiple_fills() {
+        let secrets: Vec<B256> = (1..=5).map(B256::repeat_byte).collect();
+        let leaves = HashLock::get_merkle_leaves(&secrets);
+        let hash_lock = HashLock::from_secrets(&secrets).unwrap();
+
+        assert_eq!(hash_lock.parts_count(), 4);
+        for (idx, leaf) in leaves.iter().enumerate() {
+            let root = process_proof(*leaf, &HashLock::get_proof(&leaves, idx).unwrap());
+            assert_eq!(root[2..], hash_lock.value()[2..]);
+        }
+
+        let (tree, _) = merkle_tree(&leaves[..3]);
+        let mut sorted = leaves[..3].to_vec();
+        sorted.sort();
+        assert_eq!(
+            tree[0],
+            hash_pair(hash_pair(sorted[1], sorted[0]), sorted[2])
+        );
+
+        assert!(HashLock::get_proof(&leaves, 5).is_err());
+        assert!(HashLock::for_multiple_fills(leaves[..2].to_vec()).is_err());
+    }
+
+    // Roots of @openzeppelin/merkle-tree, which builds the SimpleMerkleTree of the 1inch SDKs.
+    // Its StandardMerkleTree only adds the leaf hashing, keccak256(keccak256(abi.encode(value)))
+    #[test]
+    fn test_openzeppelin_vectors() {
+        let leaf = |account: &str, amount: u64| {
+            keccak256(keccak256(
+                (account.parse::<Address>().unwrap(), U256::from(amount)).abi_encode(),
+            ))
+        };
+
+        // README example of @openzeppelin/merkle-tree
+        let (tree, _) = merkle_tree(&[
+            leaf(
+                "0x1111111111111111111111111111111111111111",
+                5000000000000000000,
+            ),
+            leaf(
+                "0x2222222222222222222222222222222222222222",
+                2500000000000000000,
+            ),
+        ]);
+        assert_eq!(
+            tree[0],
+            b256!("0xd4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77")
+        );
+
+        // `StandardMerkleTree.of(values, ["address", "uint256"])` of the hardhat accounts
+        let leaves = [
+            leaf("0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266", 10000),
+            leaf("0x70997970C51812dc3A010C7d01b50e0d17dc79C8", 1000),
+            leaf("0x3c44cdddb6a900fa2b585dd299e03d12fa4293bc", 100),
+            leaf("0x90f79bf6eb2c4f870365e785982e1f101e93b906", 10),
+            leaf("0x15d34aaf54267db7d7c367839aaf71a00a2c6a65", 1),
+        ];
+        let (tree, _) = merkle_tree(&leaves);
+        assert_eq!(
+            tree[0],
+            b256!("0x2b4b963c699c531f94ca8f8a0ef76c5d28f067d79927c035a44296190c2d8029")
+        );
+        assert_eq!(
+            HashLock::get_proof(&leaves, 0).unwrap(),
+            vec![
+                b256!("0x8ee56d16226ff6684927054c33cd505c4eee1ebabbffe198460d00cb083aaebd"),
+                b256!("0xfa31eb8d65ff2307b7026df667a06a19aade0151ed701ed2307295ae4fa48364"),
+                b256!("0xf0768f444c5a27a6bb7c9203b0b5b147e501ff7b7784e0363e5751590962b034"),
+            ]
+        );
+    }
 }
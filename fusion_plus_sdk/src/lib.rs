@@ -5,14 +5,23 @@ pub use error::{Error, Result};
 pub mod addresses;
 pub mod api;
 pub mod auction_calculator;
+pub mod cancellation;
 pub mod chain_id;
+pub mod chain_metadata;
 pub mod constants;
 pub mod cross_chain_order;
 pub mod fusion;
 pub mod hash_lock;
 pub mod limit;
 pub mod multichain_address;
+pub mod native;
+pub mod order_summary;
 pub mod quote;
 pub mod relayer_request;
+pub mod simulation;
+#[cfg(test)]
+mod test_utils;
+pub mod tokens;
+pub mod tron;
 pub mod utils;
 pub mod whitelist;
@@ -1,7 +1,10 @@
 pub mod eip712;
+pub mod epoch_manager;
 pub mod extension;
 pub mod extension_builder;
 pub mod interaction;
 pub mod limit_order;
 pub mod maker_traits;
 pub mod order_info;
+pub mod permit;
+pub mod signature;
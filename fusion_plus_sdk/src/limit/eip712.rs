@@ -8,11 +8,28 @@ use alloy::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::{addresses::get_limit_order_contract_address, chain_id::ChainId};
+use crate::{
+    addresses::try_get_limit_order_contract_address, chain_id::ChainId,
+    multichain_address::MultichainAddress,
+};
+
+pub fn try_get_limit_order_v4_domain(chain_id: ChainId) -> crate::Result<Eip712Domain> {
+    Ok(limit_order_v4_domain(
+        chain_id,
+        try_get_limit_order_contract_address(chain_id)?,
+    ))
+}
 
 pub fn get_limit_order_v4_domain(chain_id: ChainId) -> Eip712Domain {
-    let verifying_contract = get_limit_order_contract_address(chain_id);
+    try_get_limit_order_v4_domain(chain_id).unwrap_or_else(|e| panic!("{e}"))
+}
 
+// On Tron `block.chainid` is the chain id below and addresses are hashed as their
+// 20 raw bytes, without the 0x41 prefix of the base58 form
+pub fn limit_order_v4_domain(
+    chain_id: ChainId,
+    verifying_contract: MultichainAddress,
+) -> Eip712Domain {
     eip712_domain! {
         name: "1inch Aggregation Router",
         version: "6",
@@ -22,7 +39,7 @@ pub fn get_limit_order_v4_domain(chain_id: ChainId) -> Eip712Domain {
 }
 
 sol! {
-    #[derive(Default, Debug, Serialize, Deserialize)]
+    #[derive(Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
     struct Order {
         uint256 salt;
         address maker;
@@ -38,12 +55,64 @@ sol! {
 pub type LimitOrderV4 = Order;
 
 impl LimitOrderV4 {
-    pub fn get_order_hash(&self, chain_id: ChainId) -> B256 {
-        let domain = get_limit_order_v4_domain(chain_id);
-        self.eip712_signing_hash(&domain)
+    // Fails for chains without a limit order protocol deployment, e.g. Tron
+    pub fn get_order_hash(&self, chain_id: ChainId) -> crate::Result<B256> {
+        let domain = try_get_limit_order_v4_domain(chain_id)?;
+        Ok(self.eip712_signing_hash(&domain))
     }
 }
 
 pub trait OrderBuildable: Clone + Debug {
     fn build(&self) -> LimitOrderV4;
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        primitives::{U256, keccak256},
+        sol_types::SolValue,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_tron_domain() {
+        let verifying_contract: MultichainAddress =
+            "TJLRfJUAHPRxoizJeyYFFZ7nEHit4L9FfE".parse().unwrap();
+        let domain = limit_order_v4_domain(ChainId::Tron, verifying_contract);
+
+        assert_eq!(domain.chain_id, Some(U256::from(728126428)));
+        assert_eq!(
+            domain.verifying_contract,
+            Some(
+                "0x5bc44f18b91f55540d11d612c08e4faad619eb55"
+                    .parse()
+                    .unwrap()
+            )
+        );
+
+        let expected = keccak256(
+            (
+                keccak256(
+                    "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)",
+                ),
+                keccak256("1inch Aggregation Router"),
+                keccak256("6"),
+                U256::from(728126428),
+                verifying_contract.as_raw(),
+            )
+                .abi_encode(),
+        );
+        assert_eq!(domain.separator(), expected);
+
+        // no limit order protocol deployment on Tron yet
+        assert!(matches!(
+            try_get_limit_order_v4_domain(ChainId::Tron),
+            Err(crate::Error::UnsupportedChainId(728126428))
+        ));
+        assert!(matches!(
+            LimitOrderV4::default().get_order_hash(ChainId::Tron),
+            Err(crate::Error::UnsupportedChainId(728126428))
+        ));
+    }
+}
@@ -0,0 +1,132 @@
+use alloy::{
+    network::TransactionBuilder,
+    primitives::{Address, B256, U256, aliases::U96},
+    providers::Provider,
+    rpc::types::TransactionRequest,
+    sol,
+    sol_types::SolCall,
+};
+
+use crate::{
+    addresses::get_limit_order_contract_address, chain_id::ChainId, constants::UINT_40_MAX,
+};
+
+// The limit order protocol tracks an epoch per maker and series, orders built with
+// `MakerTraits::with_epoch` are only fillable while their epoch matches the current one
+sol! {
+    #[sol(rpc)]
+    interface IEpochManager {
+        function epoch(address maker, uint96 series) external view returns (uint256);
+        function increaseEpoch(uint96 series) external;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SeriesEpoch {
+    pub series: u64,
+    pub epoch: u64,
+}
+
+pub async fn get_epoch(
+    provider: &impl Provider,
+    chain_id: ChainId,
+    maker: Address,
+    series: u64,
+) -> crate::Result<SeriesEpoch> {
+    let epoch = IEpochManager::new(
+        get_limit_order_contract_address(chain_id).as_raw(),
+        provider,
+    )
+    .epoch(maker, U96::from(series))
+    .call()
+    .await
+    .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+
+    // maker traits only hold 40 bits of the epoch
+    if epoch > U256::from(UINT_40_MAX) {
+        return Err(crate::Error::InvalidMakerTraits(format!(
+            "epoch {epoch} of series {series} does not fit in uint40"
+        )));
+    }
+
+    Ok(SeriesEpoch {
+        series,
+        epoch: epoch.to::<u64>(),
+    })
+}
+
+// Invalidates every outstanding order of the sender in `series`
+pub fn increase_epoch_tx(chain_id: ChainId, series: u64) -> TransactionRequest {
+    let call = IEpochManager::increaseEpochCall {
+        series: U96::from(series),
+    };
+
+    TransactionRequest::default()
+        .with_to(get_limit_order_contract_address(chain_id).as_raw())
+        .with_input(call.abi_encode())
+}
+
+pub async fn increase_epoch(
+    provider: &impl Provider,
+    chain_id: ChainId,
+    series: u64,
+) -> crate::Result<B256> {
+    let receipt = provider
+        .send_transaction(increase_epoch_tx(chain_id, series))
+        .await?
+        .get_receipt()
+        .await
+        .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+
+    if !receipt.status() {
+        return Err(crate::Error::InternalError(format!(
+            "increaseEpoch transaction {} reverted",
+            receipt.transaction_hash
+        )));
+    }
+
+    Ok(receipt.transaction_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        primitives::{Bytes, address},
+        providers::ProviderBuilder,
+        transports::mock::Asserter,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_increase_epoch_tx() {
+        let tx = increase_epoch_tx(ChainId::Ethereum, 7);
+        let call = IEpochManager::increaseEpochCall::abi_decode(tx.input.input().unwrap()).unwrap();
+
+        assert_eq!(call.series, U96::from(7));
+    }
+
+    #[tokio::test]
+    async fn test_get_epoch() {
+        let maker = address!("0x00000000000000000000000000000000deadbeef");
+
+        let asserter = Asserter::new();
+        asserter.push_success(&Bytes::from(U256::from(5).to_be_bytes::<32>()));
+        asserter.push_success(&Bytes::from(U256::MAX.to_be_bytes::<32>()));
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter);
+
+        assert_eq!(
+            get_epoch(&provider, ChainId::Ethereum, maker, 7)
+                .await
+                .unwrap(),
+            SeriesEpoch {
+                series: 7,
+                epoch: 5
+            }
+        );
+        assert!(matches!(
+            get_epoch(&provider, ChainId::Ethereum, maker, 7).await,
+            Err(crate::Error::InvalidMakerTraits(_))
+        ));
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
     utils::bytes_iter::{BytesIter, Side},
 };
 
-#[derive(Default)]
+#[derive(Clone, Debug, Default)]
 pub struct Extension {
     pub maker_asset_suffix: Bytes,
     pub taker_asset_suffix: Bytes,
@@ -100,22 +100,26 @@ impl Extension {
         }
     }
 
-    pub fn decode_from(bytes: Bytes) -> Self {
+    pub fn decode_from(bytes: Bytes) -> crate::Result<Self> {
         if bytes.is_empty() {
-            return Self::default();
+            return Ok(Self::default());
         }
 
         let mut extension = Self::default();
 
-        let mut iter = BytesIter::new(bytes);
+        let mut iter = BytesIter::new(bytes, "extension");
 
-        let mut offsets = iter.next_uint256(Side::Front);
+        let mut offsets = iter.next_uint256(Side::Front)?;
         let mut consumed = 0;
 
         for data in extension.get_all_mut() {
             let offset = (offsets & U256::from(UINT_32_MAX)).to::<usize>();
-            let bytes_count = offset - consumed;
-            *data = iter.next_bytes(bytes_count, Side::Front);
+            let bytes_count = offset.checked_sub(consumed).ok_or_else(|| {
+                iter.error(format!(
+                    "offset {offset} is before the end of previous field {consumed}"
+                ))
+            })?;
+            *data = iter.next_bytes(bytes_count, Side::Front)?;
 
             consumed += bytes_count;
             offsets >>= 32;
@@ -123,7 +127,7 @@ impl Extension {
 
         extension.custom_data = iter.rest();
 
-        extension
+        Ok(extension)
     }
 }
 
@@ -146,7 +150,7 @@ mod tests {
         };
 
         let encoded = extension.encode();
-        let decoded = Extension::decode_from(encoded);
+        let decoded = Extension::decode_from(encoded).unwrap();
 
         assert_eq!(extension.maker_asset_suffix, decoded.maker_asset_suffix);
         assert_eq!(extension.taker_asset_suffix, decoded.taker_asset_suffix);
@@ -158,4 +162,37 @@ mod tests {
         assert_eq!(extension.post_interaction, decoded.post_interaction);
         assert_eq!(extension.custom_data, decoded.custom_data);
     }
+
+    #[test]
+    fn decode_malformed() {
+        // offsets point past the end of the data
+        let mut bytes = [0u8; 32];
+        bytes[31] = 10;
+        let err = Extension::decode_from(bytes.to_vec().into()).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::Decode {
+                field: "extension",
+                offset: 32,
+                ..
+            }
+        ));
+
+        // second offset is smaller than the first one
+        let mut bytes = [0u8; 36];
+        bytes[31] = 4;
+        bytes[27] = 2;
+        let err = Extension::decode_from(bytes.to_vec().into()).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::Decode {
+                field: "extension",
+                offset: 36,
+                ..
+            }
+        ));
+
+        // too short to hold the offsets
+        assert!(Extension::decode_from(vec![1u8; 31].into()).is_err());
+    }
 }
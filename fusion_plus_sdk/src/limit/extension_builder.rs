@@ -9,6 +9,12 @@ use crate::{
 
 pub trait ExtensionBuildable: Clone + Debug {
     fn build(&self) -> Extension;
+
+    // Whether the order receiver has to be the settlement extension, which then takes
+    // the integrator fee out of the taker asset and forwards the rest to the custom receiver
+    fn receives_taker_asset(&self) -> bool {
+        true
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -19,15 +19,15 @@ impl Interaction {
             .into()
     }
 
-    pub fn decode_from(bytes: Bytes) -> Self {
-        let mut iter = BytesIter::new(bytes);
+    pub fn decode_from(bytes: Bytes, field: &'static str) -> crate::Result<Self> {
+        let mut iter = BytesIter::new(bytes, field);
 
-        let target = iter.next_address(Side::Front);
+        let target = iter.next_address(Side::Front)?;
         let data = iter.rest();
 
-        Self {
+        Ok(Self {
             target: MultichainAddress::from_raw(target),
             data,
-        }
+        })
     }
 }
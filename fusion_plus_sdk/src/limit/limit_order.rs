@@ -78,11 +78,25 @@ impl LimitOrder {
         }
     }
 
+    pub fn from_v4(order: &LimitOrderV4) -> Self {
+        Self {
+            salt: order.salt,
+            maker: MultichainAddress::from_raw(order.maker),
+            receiver: MultichainAddress::from_raw(order.receiver),
+            maker_asset: MultichainAddress::from_raw(order.makerAsset),
+            taker_asset: MultichainAddress::from_raw(order.takerAsset),
+            making_amount: order.makingAmount,
+            taking_amount: order.takingAmount,
+            maker_traits: MakerTraits::new(order.makerTraits),
+        }
+    }
+
     pub fn to_v4(&self) -> LimitOrderV4 {
         LimitOrderV4 {
             salt: self.salt,
             maker: self.maker.as_raw(),
-            receiver: self.receiver.as_raw(),
+            // a Solana receiver keeps its high 12 bytes in the escrow extension
+            receiver: self.receiver.low_20_bytes(),
             makerAsset: self.maker_asset.as_raw(),
             takerAsset: self.taker_asset.as_raw(),
             makingAmount: self.making_amount,
@@ -91,7 +105,7 @@ impl LimitOrder {
         }
     }
 
-    pub fn get_order_hash(&self, chain_id: ChainId) -> B256 {
+    pub fn get_order_hash(&self, chain_id: ChainId) -> crate::Result<B256> {
         self.to_v4().get_order_hash(chain_id)
     }
 
@@ -1,6 +1,6 @@
 use alloy::primitives::{Address, FixedBytes, U256};
 
-use crate::utils::bit_mask::BitMask;
+use crate::{constants::UINT_40_MAX, utils::bit_mask::BitMask};
 
 fn allowed_sender_mask() -> BitMask {
     BitMask::new(0, Some(80))
@@ -32,6 +32,11 @@ const HAS_EXTENSION_FLAG: usize = 249;
 const USE_PERMIT2_FLAG: usize = 248;
 const UNWRAP_WETH_FLAG: usize = 247;
 
+// bits 200..247 and 253 are not used by the protocol
+fn reserved_mask() -> U256 {
+    (BitMask::new(200, Some(247)).to_u256()) | BitMask::new(253, None).to_u256()
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct MakerTraits {
     value: U256,
@@ -52,7 +57,6 @@ impl MakerTraits {
         self.value >> mask.offset & mask.mask
     }
 
-    // TODO add test for this
     fn set_mask(&self, mask: BitMask, val: U256) -> U256 {
         (self.value & !(mask.mask << mask.offset)) | ((val & mask.mask) << mask.offset)
     }
@@ -90,34 +94,60 @@ impl MakerTraits {
         self
     }
 
+    fn try_set_uint40(mut self, mask: BitMask, name: &str, val: u64) -> crate::Result<Self> {
+        if val > UINT_40_MAX {
+            return Err(crate::Error::InvalidMakerTraits(format!(
+                "{name} {val} does not fit in uint40"
+            )));
+        }
+
+        self.value = self.set_mask(mask, U256::from(val));
+        Ok(self)
+    }
+
     pub fn expiration(&self) -> u64 {
         let val = self.get_mask(expiration_mask());
         val.to::<u64>()
     }
 
+    #[deprecated(note = "masks expirations wider than 40 bits, use try_with_expiration")]
     pub fn with_expiration(mut self, expiration: u64) -> Self {
         self.value = self.set_mask(expiration_mask(), U256::from(expiration));
         self
     }
 
+    pub fn try_with_expiration(self, expiration: u64) -> crate::Result<Self> {
+        self.try_set_uint40(expiration_mask(), "expiration", expiration)
+    }
+
     pub fn nonce_or_epoch(&self) -> u64 {
         self.get_mask(nonce_or_epoch_mask()).to::<u64>()
     }
 
+    #[deprecated(note = "masks nonces wider than 40 bits, use try_with_nonce")]
     pub fn with_nonce(mut self, nonce: u64) -> Self {
         self.value = self.set_mask(nonce_or_epoch_mask(), U256::from(nonce));
         self
     }
 
+    pub fn try_with_nonce(self, nonce: u64) -> crate::Result<Self> {
+        self.try_set_uint40(nonce_or_epoch_mask(), "nonce", nonce)
+    }
+
     pub fn series(&self) -> u64 {
         self.get_mask(series_mask()).to::<u64>()
     }
 
+    #[deprecated(note = "masks series wider than 40 bits, use try_with_series")]
     pub fn with_series(mut self, series: u64) -> Self {
         self.value = self.set_mask(series_mask(), U256::from(series));
         self
     }
 
+    pub fn try_with_series(self, series: u64) -> crate::Result<Self> {
+        self.try_set_uint40(series_mask(), "series", series)
+    }
+
     pub fn has_extension(&self) -> bool {
         self.value.bit(HAS_EXTENSION_FLAG)
     }
@@ -200,6 +230,10 @@ impl MakerTraits {
     }
 
     pub fn is_epoch_manager_enabled(&self) -> bool {
+        self.need_check_epoch_manager()
+    }
+
+    pub fn need_check_epoch_manager(&self) -> bool {
         self.value.bit(NEED_CHECK_EPOCH_MANAGER_FLAG)
     }
 
@@ -235,18 +269,237 @@ impl MakerTraits {
         !self.is_partial_fill_allowed() || !self.is_multiple_fills_allowed()
     }
 
+    #[deprecated(note = "masks series and epochs wider than 40 bits, use try_with_epoch")]
+    #[allow(deprecated)]
     pub fn with_epoch(self, series: u64, epoch: u64) -> Self {
-        let mut this = self.with_series(series);
-        this = this.with_nonce(epoch);
-        this.enable_epoch_manager_check()
+        self.with_series(series)
+            .with_nonce(epoch)
+            .enable_epoch_manager_check()
     }
 
-    fn enable_epoch_manager_check(mut self) -> Self {
-        assert!(
-            self.is_partial_fill_allowed() && self.is_multiple_fills_allowed(),
-            "Epoch manager allowed only when partialFills and multipleFills enabled"
-        );
+    pub fn try_with_epoch(self, series: u64, epoch: u64) -> crate::Result<Self> {
+        self.try_with_series(series)?
+            .try_with_nonce(epoch)?
+            .try_enable_epoch_manager_check()
+    }
+
+    pub fn enable_epoch_manager_check(self) -> Self {
+        self.try_enable_epoch_manager_check()
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    pub fn try_enable_epoch_manager_check(mut self) -> crate::Result<Self> {
+        if self.is_bit_invalidator_mode() {
+            return Err(crate::Error::InvalidMakerTraits(
+                "Epoch manager allowed only when partialFills and multipleFills enabled"
+                    .to_string(),
+            ));
+        }
         self.value.set_bit(NEED_CHECK_EPOCH_MANAGER_FLAG, true);
+        Ok(self)
+    }
+
+    pub fn disable_epoch_manager_check(mut self) -> Self {
+        self.value.set_bit(NEED_CHECK_EPOCH_MANAGER_FLAG, false);
         self
     }
+
+    // Catches combinations the limit order protocol rejects or silently ignores,
+    // e.g. when the value was assembled with raw bit operations or decoded from an order
+    pub fn validate(&self) -> crate::Result<()> {
+        if self.need_check_epoch_manager() && self.is_bit_invalidator_mode() {
+            return Err(crate::Error::InvalidMakerTraits(
+                "Epoch manager allowed only when partialFills and multipleFills enabled"
+                    .to_string(),
+            ));
+        }
+
+        if !(self.value & reserved_mask()).is_zero() {
+            return Err(crate::Error::InvalidMakerTraits(format!(
+                "reserved bits set in {:#x}",
+                self.value
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    #[test]
+    fn test_set_mask() {
+        let traits = MakerTraits::new(U256::MAX);
+        let value = traits.set_mask(expiration_mask(), U256::from(0x1234));
+
+        assert_eq!(
+            MakerTraits::new(value).get_mask(expiration_mask()),
+            U256::from(0x1234)
+        );
+        // bits outside the mask are preserved
+        assert_eq!(
+            value | expiration_mask().to_u256(),
+            U256::MAX,
+            "only the expiration bits may change"
+        );
+    }
+
+    #[test]
+    fn test_allowed_sender() {
+        let sender = address!("0x00000000000000000000000000000000deadbeef");
+        let traits = MakerTraits::default();
+        assert!(!traits.is_private());
+
+        let traits = traits.with_allowed_sender(sender);
+        assert!(traits.is_private());
+        assert_eq!(traits.allowed_sender().as_slice(), &sender.as_slice()[10..]);
+        assert!(!traits.with_any_sender().is_private());
+    }
+
+    #[test]
+    fn test_uint40_fields() {
+        let traits = MakerTraits::default()
+            .try_with_expiration(UINT_40_MAX)
+            .unwrap()
+            .try_with_nonce(42)
+            .unwrap()
+            .try_with_series(7)
+            .unwrap();
+
+        assert_eq!(traits.expiration(), UINT_40_MAX);
+        assert_eq!(traits.nonce_or_epoch(), 42);
+        assert_eq!(traits.series(), 7);
+
+        for result in [
+            traits.clone().try_with_expiration(UINT_40_MAX + 1),
+            traits.clone().try_with_nonce(UINT_40_MAX + 1),
+            traits.clone().try_with_series(UINT_40_MAX + 1),
+        ] {
+            assert!(matches!(result, Err(crate::Error::InvalidMakerTraits(_))));
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_masking_setters() {
+        let traits = MakerTraits::default()
+            .with_expiration(UINT_40_MAX + 2)
+            .with_nonce(UINT_40_MAX + 3)
+            .with_series(UINT_40_MAX + 4);
+
+        assert_eq!(traits.expiration(), 1);
+        assert_eq!(traits.nonce_or_epoch(), 2);
+        assert_eq!(traits.series(), 3);
+
+        let traits = MakerTraits::default()
+            .allow_multiple_fills()
+            .with_epoch(UINT_40_MAX + 1, 9);
+        assert_eq!(traits.series(), 0);
+        assert_eq!(traits.nonce_or_epoch(), 9);
+        assert!(traits.need_check_epoch_manager());
+    }
+
+    #[test]
+    fn test_flags() {
+        type Getter = fn(&MakerTraits) -> bool;
+        type Setter = fn(MakerTraits) -> MakerTraits;
+
+        let cases: [(Getter, Setter, Setter); 5] = [
+            (
+                MakerTraits::is_multiple_fills_allowed,
+                MakerTraits::allow_multiple_fills,
+                MakerTraits::disable_multiple_fills,
+            ),
+            (
+                MakerTraits::has_pre_interaction,
+                MakerTraits::enable_pre_interaction,
+                MakerTraits::disable_pre_interaction,
+            ),
+            (
+                MakerTraits::has_post_interaction,
+                MakerTraits::enable_post_interaction,
+                MakerTraits::disable_post_interaction,
+            ),
+            (
+                MakerTraits::is_permit2,
+                MakerTraits::enable_permit2,
+                MakerTraits::disable_permit2,
+            ),
+            (
+                MakerTraits::is_native_unwrap_enabled,
+                MakerTraits::enable_native_unwrap,
+                MakerTraits::disable_native_unwrap,
+            ),
+        ];
+
+        for (get, enable, disable) in cases {
+            let traits = MakerTraits::default();
+            assert!(!get(&traits));
+
+            let enabled = enable(traits);
+            assert!(get(&enabled));
+            assert_eq!(
+                enabled.as_u256().count_ones(),
+                1,
+                "a flag must set a single bit"
+            );
+            assert!(!get(&disable(enabled)));
+        }
+
+        assert!(!MakerTraits::default().has_extension());
+        assert!(MakerTraits::default().with_extension().has_extension());
+    }
+
+    #[test]
+    fn test_partial_fills() {
+        let traits = MakerTraits::default();
+        assert!(traits.is_partial_fill_allowed());
+
+        let traits = traits.disable_partial_fills();
+        assert!(!traits.is_partial_fill_allowed());
+        assert!(traits.is_bit_invalidator_mode());
+        assert!(traits.set_partial_fills(true).is_partial_fill_allowed());
+    }
+
+    #[test]
+    fn test_epoch_manager() {
+        let traits = MakerTraits::default()
+            .allow_multiple_fills()
+            .try_with_epoch(3, 9)
+            .unwrap();
+
+        assert!(traits.need_check_epoch_manager());
+        assert!(traits.is_epoch_manager_enabled());
+        assert_eq!(traits.series(), 3);
+        assert_eq!(traits.nonce_or_epoch(), 9);
+        assert!(traits.validate().is_ok());
+        assert!(
+            !traits
+                .disable_epoch_manager_check()
+                .need_check_epoch_manager()
+        );
+
+        let result = MakerTraits::default().try_with_epoch(3, 9);
+        assert!(matches!(result, Err(crate::Error::InvalidMakerTraits(_))));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(MakerTraits::default().validate().is_ok());
+
+        let mut value = U256::ZERO;
+        value.set_bit(NEED_CHECK_EPOCH_MANAGER_FLAG, true);
+        let err = MakerTraits::new(value).validate().unwrap_err();
+        assert!(
+            matches!(err, crate::Error::InvalidMakerTraits(msg) if msg.starts_with("Epoch manager"))
+        );
+
+        let mut value = U256::ZERO;
+        value.set_bit(210, true);
+        assert!(MakerTraits::new(value).validate().is_err());
+    }
 }
@@ -0,0 +1,379 @@
+use alloy::{
+    dyn_abi::Eip712Domain,
+    primitives::{
+        Address, B256, Bytes, Signature, U256,
+        aliases::{U48, U160},
+    },
+    providers::Provider,
+    signers::Signer,
+    sol,
+    sol_types::{SolStruct, SolValue, eip712_domain},
+};
+
+use crate::{
+    addresses::{get_limit_order_contract_address, get_permit2_contract_address},
+    chain_id::ChainId,
+    constants::UINT_160_MAX,
+};
+
+sol! {
+    #[sol(rpc)]
+    interface IERC20Permit {
+        function name() external view returns (string);
+        function version() external view returns (string);
+        function nonces(address owner) external view returns (uint256);
+    }
+
+    #[sol(rpc)]
+    interface IPermit2 {
+        function allowance(address user, address token, address spender) external view returns (uint160 amount, uint48 expiration, uint48 nonce);
+    }
+
+    #[derive(Debug)]
+    struct Permit {
+        address owner;
+        address spender;
+        uint256 value;
+        uint256 nonce;
+        uint256 deadline;
+    }
+
+    #[derive(Debug)]
+    struct PermitDetails {
+        address token;
+        uint160 amount;
+        uint48 expiration;
+        uint48 nonce;
+    }
+
+    #[derive(Debug)]
+    struct PermitSingle {
+        PermitDetails details;
+        address spender;
+        uint256 sigDeadline;
+    }
+}
+
+// Signed permit in the layout the limit order protocol passes to `SafeERC20.tryPermit`,
+// it becomes the `maker_permit` interaction of the order extension
+#[derive(Clone, Debug)]
+pub struct MakerPermit {
+    pub data: Bytes,
+    pub is_permit2: bool,
+}
+
+// EIP-2612 permit letting the limit order protocol spend `value` of the maker's tokens
+#[derive(Debug)]
+pub struct Eip2612Permit {
+    pub permit: Permit,
+    pub domain: Eip712Domain,
+}
+
+// Permit2 allowance for the limit order protocol, the maker must have approved
+// Permit2 on the token once, after that orders need no further approvals
+#[derive(Debug)]
+pub struct Permit2Single {
+    pub owner: Address,
+    pub permit: PermitSingle,
+    pub domain: Eip712Domain,
+}
+
+// Signs the typed permit rather than its hash, so wallets implementing `sign_typed_data`
+// (e.g. hardware wallets) can show the user what they approve, as `PreparedOrder::sign` does
+async fn sign_typed_data<T: SolStruct + Send + Sync>(
+    signer: &(impl Signer + Sync),
+    owner: Address,
+    permit: &T,
+    domain: &Eip712Domain,
+) -> crate::Result<Signature> {
+    if signer.address() != owner {
+        return Err(crate::Error::InvalidSignature(format!(
+            "signer {} is not the permit owner {owner}",
+            signer.address()
+        )));
+    }
+
+    signer
+        .sign_typed_data(permit, domain)
+        .await
+        .map_err(|e| crate::Error::InternalError(e.to_string()))
+}
+
+impl Eip2612Permit {
+    pub fn new(
+        chain_id: ChainId,
+        token: Address,
+        token_name: String,
+        token_version: String,
+        permit: Permit,
+    ) -> Self {
+        Self {
+            permit,
+            domain: eip712_domain! {
+                name: token_name,
+                version: token_version,
+                chain_id: chain_id as u64,
+                verifying_contract: token,
+            },
+        }
+    }
+
+    // Reads the token name, version and the owner's nonce, tokens without `version()` use "1"
+    pub async fn fetch(
+        provider: &impl Provider,
+        chain_id: ChainId,
+        token: Address,
+        owner: Address,
+        value: U256,
+        deadline: u64,
+    ) -> crate::Result<Self> {
+        let contract = IERC20Permit::new(token, provider);
+
+        let name = contract
+            .name()
+            .call()
+            .await
+            .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+        let version = contract
+            .version()
+            .call()
+            .await
+            .unwrap_or_else(|_| "1".to_string());
+        let nonce = contract
+            .nonces(owner)
+            .call()
+            .await
+            .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+
+        Ok(Self::new(
+            chain_id,
+            token,
+            name,
+            version,
+            Permit {
+                owner,
+                spender: get_limit_order_contract_address(chain_id).as_raw(),
+                value,
+                nonce,
+                deadline: U256::from(deadline),
+            },
+        ))
+    }
+
+    pub fn signing_hash(&self) -> B256 {
+        self.permit.eip712_signing_hash(&self.domain)
+    }
+
+    // abi.encode(owner, spender, value, deadline, v, r, s)
+    pub fn encode(&self, signature: &Signature) -> MakerPermit {
+        let data = (
+            self.permit.owner,
+            self.permit.spender,
+            self.permit.value,
+            self.permit.deadline,
+            U256::from(27 + signature.v() as u8),
+            B256::from(signature.r()),
+            B256::from(signature.s()),
+        )
+            .abi_encode_params();
+
+        MakerPermit {
+            data: data.into(),
+            is_permit2: false,
+        }
+    }
+
+    pub async fn sign(&self, signer: &(impl Signer + Sync)) -> crate::Result<MakerPermit> {
+        let signature =
+            sign_typed_data(signer, self.permit.owner, &self.permit, &self.domain).await?;
+        Ok(self.encode(&signature))
+    }
+}
+
+impl Permit2Single {
+    pub fn new(
+        chain_id: ChainId,
+        owner: Address,
+        details: PermitDetails,
+        sig_deadline: u64,
+    ) -> Self {
+        Self {
+            owner,
+            permit: PermitSingle {
+                details,
+                spender: get_limit_order_contract_address(chain_id).as_raw(),
+                sigDeadline: U256::from(sig_deadline),
+            },
+            domain: eip712_domain! {
+                name: "Permit2",
+                chain_id: chain_id as u64,
+                verifying_contract: get_permit2_contract_address(chain_id).as_raw(),
+            },
+        }
+    }
+
+    // Reads the current Permit2 nonce of the owner, token and limit order protocol
+    pub async fn fetch(
+        provider: &impl Provider,
+        chain_id: ChainId,
+        token: Address,
+        owner: Address,
+        amount: U256,
+        expiration: u64,
+    ) -> crate::Result<Self> {
+        if amount > UINT_160_MAX {
+            return Err(crate::Error::InternalErrorStr(
+                "Permit2 amount does not fit in uint160",
+            ));
+        }
+
+        let allowance = IPermit2::new(get_permit2_contract_address(chain_id).as_raw(), provider)
+            .allowance(
+                owner,
+                token,
+                get_limit_order_contract_address(chain_id).as_raw(),
+            )
+            .call()
+            .await
+            .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+
+        Ok(Self::new(
+            chain_id,
+            owner,
+            PermitDetails {
+                token,
+                amount: amount.to::<U160>(),
+                expiration: U48::from(expiration),
+                nonce: allowance.nonce,
+            },
+            expiration,
+        ))
+    }
+
+    pub fn signing_hash(&self) -> B256 {
+        self.permit.eip712_signing_hash(&self.domain)
+    }
+
+    // abi.encode(owner, permitSingle, signature) with a 64 byte EIP-2098 signature
+    pub fn encode(&self, signature: &Signature) -> MakerPermit {
+        let data = (
+            self.owner,
+            self.permit.clone(),
+            Bytes::from(signature.as_erc2098()),
+        )
+            .abi_encode_params();
+
+        MakerPermit {
+            data: data.into(),
+            is_permit2: true,
+        }
+    }
+
+    pub async fn sign(&self, signer: &(impl Signer + Sync)) -> crate::Result<MakerPermit> {
+        let signature = sign_typed_data(signer, self.owner, &self.permit, &self.domain).await?;
+        Ok(self.encode(&signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{primitives::address, signers::local::PrivateKeySigner, sol_types::SolType};
+
+    use super::*;
+
+    fn signer() -> PrivateKeySigner {
+        "0x0123456789012345678901234567890123456789012345678901234567890123"
+            .parse()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_eip2612_permit() {
+        let signer = signer();
+        let token = address!("0xaf88d065e77c8cC2239327C5EDb3A432268e5831");
+        let permit = Eip2612Permit::new(
+            ChainId::Arbitrum,
+            token,
+            "USD Coin".to_string(),
+            "2".to_string(),
+            Permit {
+                owner: signer.address(),
+                spender: get_limit_order_contract_address(ChainId::Arbitrum).as_raw(),
+                value: U256::from(1_000_000),
+                nonce: U256::ZERO,
+                deadline: U256::from(1_800_000_000),
+            },
+        );
+
+        let maker_permit = permit.sign(&signer).await.unwrap();
+        assert!(!maker_permit.is_permit2);
+        assert_eq!(maker_permit.data.len(), 224);
+
+        type Encoded = (
+            alloy::sol_types::sol_data::Address,
+            alloy::sol_types::sol_data::Address,
+            alloy::sol_types::sol_data::Uint<256>,
+            alloy::sol_types::sol_data::Uint<256>,
+            alloy::sol_types::sol_data::Uint<8>,
+            alloy::sol_types::sol_data::FixedBytes<32>,
+            alloy::sol_types::sol_data::FixedBytes<32>,
+        );
+        let (owner, spender, value, deadline, v, r, s) =
+            Encoded::abi_decode_params(&maker_permit.data).unwrap();
+        assert_eq!(owner, signer.address());
+        assert_eq!(spender, permit.permit.spender);
+        assert_eq!(value, U256::from(1_000_000));
+        assert_eq!(deadline, U256::from(1_800_000_000));
+
+        let signature = Signature::from_scalars_and_parity(r, s, v == 28);
+        assert_eq!(
+            signature
+                .recover_address_from_prehash(&permit.signing_hash())
+                .unwrap(),
+            signer.address()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_permit2() {
+        let signer = signer();
+        let permit = Permit2Single::new(
+            ChainId::Ethereum,
+            signer.address(),
+            PermitDetails {
+                token: address!("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"),
+                amount: U160::from(1_000_000),
+                expiration: U48::from(1_800_000_000),
+                nonce: U48::from(3),
+            },
+            1_800_000_000,
+        );
+
+        let maker_permit = permit.sign(&signer).await.unwrap();
+        assert!(maker_permit.is_permit2);
+        assert_eq!(maker_permit.data.len(), 352);
+
+        let (owner, decoded, signature) =
+            <(Address, PermitSingle, Bytes)>::abi_decode_params(&maker_permit.data).unwrap();
+        assert_eq!(owner, signer.address());
+        assert_eq!(decoded.details.nonce, U48::from(3));
+        assert_eq!(
+            decoded.spender,
+            get_limit_order_contract_address(ChainId::Ethereum).as_raw()
+        );
+
+        let signature = Signature::from_erc2098(&signature);
+        assert_eq!(
+            signature
+                .recover_address_from_prehash(&permit.signing_hash())
+                .unwrap(),
+            signer.address()
+        );
+
+        let other = PrivateKeySigner::random();
+        assert!(matches!(
+            permit.sign(&other).await,
+            Err(crate::Error::InvalidSignature(_))
+        ));
+    }
+}
@@ -0,0 +1,169 @@
+use alloy::{
+    primitives::{Address, B256, Bytes, FixedBytes, Signature},
+    providers::Provider,
+    sol,
+};
+
+sol! {
+    #[sol(rpc)]
+    interface IERC1271 {
+        function isValidSignature(bytes32 hash, bytes signature) external view returns (bytes4 magicValue);
+    }
+}
+
+// bytes4(keccak256("isValidSignature(bytes32,bytes)"))
+pub const ERC1271_MAGIC_VALUE: FixedBytes<4> = FixedBytes([0x16, 0x26, 0xba, 0x7e]);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OrderSignature {
+    // ECDSA signature of the maker key over the EIP-712 order hash
+    Eoa(Signature),
+    // Signature in whatever format the maker contract accepts in `isValidSignature`
+    Contract(Bytes),
+}
+
+impl OrderSignature {
+    pub fn is_maker_contract(&self) -> bool {
+        matches!(self, OrderSignature::Contract(_))
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        match self {
+            OrderSignature::Eoa(signature) => signature.as_bytes().into(),
+            OrderSignature::Contract(signature) => signature.clone(),
+        }
+    }
+
+    // Checks ECDSA signatures locally and contract signatures with an `isValidSignature` call
+    pub async fn verify(
+        &self,
+        provider: &impl Provider,
+        maker: Address,
+        order_hash: B256,
+    ) -> crate::Result<()> {
+        match self {
+            OrderSignature::Eoa(signature) => verify_eoa_signature(signature, maker, order_hash),
+            OrderSignature::Contract(signature) => {
+                if is_valid_contract_signature(provider, maker, order_hash, signature.clone())
+                    .await?
+                {
+                    Ok(())
+                } else {
+                    Err(crate::Error::InvalidSignature(format!(
+                        "maker contract {maker} rejected the signature of {order_hash}"
+                    )))
+                }
+            }
+        }
+    }
+}
+
+pub fn verify_eoa_signature(
+    signature: &Signature,
+    maker: Address,
+    order_hash: B256,
+) -> crate::Result<()> {
+    let signer = signature
+        .recover_address_from_prehash(&order_hash)
+        .map_err(|e| crate::Error::InvalidSignature(e.to_string()))?;
+    if signer != maker {
+        return Err(crate::Error::InvalidSignature(format!(
+            "signed by {signer}, order maker is {maker}"
+        )));
+    }
+
+    Ok(())
+}
+
+// Reverting calls and accounts without code count as invalid signatures,
+// failures of the node are returned as errors
+pub async fn is_valid_contract_signature(
+    provider: &impl Provider,
+    maker: Address,
+    order_hash: B256,
+    signature: Bytes,
+) -> crate::Result<bool> {
+    let result = IERC1271::new(maker, provider)
+        .isValidSignature(order_hash, signature)
+        .call()
+        .await;
+
+    match result {
+        Ok(magic) => Ok(magic == ERC1271_MAGIC_VALUE),
+        Err(alloy::contract::Error::ZeroData(..)) => Ok(false),
+        Err(alloy::contract::Error::TransportError(e)) => {
+            if e.as_error_resp()
+                .is_some_and(|payload| payload.message.contains("revert"))
+            {
+                Ok(false)
+            } else {
+                Err(e.into())
+            }
+        }
+        Err(e) => Err(crate::Error::InternalError(e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        primitives::{U256, address},
+        providers::ProviderBuilder,
+        sol_types::SolValue,
+        transports::mock::Asserter,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_contract_signature() {
+        let maker = address!("0x00000000000000000000000000000000deadbeef");
+        let signature = OrderSignature::Contract(Bytes::from_static(&[1, 2, 3]));
+        assert!(signature.is_maker_contract());
+        assert_eq!(signature.to_bytes(), Bytes::from_static(&[1, 2, 3]));
+
+        let asserter = Asserter::new();
+        asserter.push_success(&Bytes::from(ERC1271_MAGIC_VALUE.abi_encode()));
+        asserter.push_success(&Bytes::from(U256::ZERO.to_be_bytes::<32>()));
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter);
+
+        assert!(
+            signature
+                .verify(&provider, maker, B256::repeat_byte(1))
+                .await
+                .is_ok()
+        );
+        assert!(matches!(
+            signature
+                .verify(&provider, maker, B256::repeat_byte(1))
+                .await,
+            Err(crate::Error::InvalidSignature(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_contract_signature_errors() {
+        let maker = address!("0x00000000000000000000000000000000deadbeef");
+        let signature = Bytes::from_static(&[1, 2, 3]);
+        let failure = |code: i64, message: &str| {
+            serde_json::from_value(serde_json::json!({ "code": code, "message": message })).unwrap()
+        };
+
+        let asserter = Asserter::new();
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+        let is_valid = || {
+            is_valid_contract_signature(&provider, maker, B256::repeat_byte(1), signature.clone())
+        };
+
+        // maker without code
+        asserter.push_success(&Bytes::new());
+        assert!(!is_valid().await.unwrap());
+
+        asserter.push_failure(failure(3, "execution reverted"));
+        assert!(!is_valid().await.unwrap());
+
+        // the node failing is not a verdict on the signature
+        asserter.push_failure(failure(-32005, "rate limit exceeded"));
+        assert!(matches!(is_valid().await, Err(crate::Error::Transport(_))));
+    }
+}
@@ -1,7 +1,7 @@
 use std::{fmt::Display, str::FromStr};
 
 use alloy::{
-    primitives::Address,
+    primitives::{Address, B256, FixedBytes},
     signers::k256::sha2::{Digest, Sha256},
 };
 use serde::{Deserialize, Serialize};
@@ -17,6 +17,10 @@ pub enum MultichainAddress {
     Tron {
         raw: Address,
     },
+    // 32 byte public key, only valid as destination of cross chain orders
+    Solana {
+        raw: B256,
+    },
 }
 
 impl MultichainAddress {
@@ -39,17 +43,66 @@ impl MultichainAddress {
         }
     }
 
+    // 20 byte address of EVM chains and Tron. Solana keys do not fit, use `low_20_bytes`
+    // together with `first_part` where a key is split over two fields
+    pub fn try_as_raw(&self) -> crate::Result<Address> {
+        match self {
+            MultichainAddress::Solana { .. } => Err(crate::Error::InternalError(format!(
+                "the Solana key {self} does not fit in 20 bytes, use low_20_bytes"
+            ))),
+            _ => Ok(self.low_20_bytes()),
+        }
+    }
+
     pub fn as_raw(&self) -> Address {
+        self.try_as_raw().unwrap()
+    }
+
+    // Low 20 bytes of the address word, for Solana the part of the key that fits in an EVM address
+    pub fn low_20_bytes(&self) -> Address {
         match self {
             MultichainAddress::Ethereum { raw, .. } => *raw,
             MultichainAddress::Tron { raw } => *raw,
+            MultichainAddress::Solana { raw } => Address::from_slice(&raw[12..]),
+        }
+    }
+
+    // Address as one abi word, 20 byte addresses are left padded with zeros
+    pub fn as_bytes32(&self) -> B256 {
+        match self {
+            MultichainAddress::Solana { raw } => *raw,
+            _ => self.as_raw().into_word(),
+        }
+    }
+
+    // High 12 bytes of the key which do not fit in `low_20_bytes`, zero for 20 byte addresses
+    pub fn first_part(&self) -> FixedBytes<12> {
+        FixedBytes::from_slice(&self.as_bytes32()[..12])
+    }
+
+    // Reads an address of `chain_id` from an abi word
+    pub fn from_bytes32(chain_id: ChainId, word: B256) -> crate::Result<Self> {
+        if chain_id == ChainId::Solana {
+            return Ok(MultichainAddress::Solana { raw: word });
         }
+        if !word[..12].iter().all(|byte| *byte == 0) {
+            return Err(crate::Error::MultichainAddressDecodeFailed(format!(
+                "{word} does not fit in a 20 byte address of {chain_id}"
+            )));
+        }
+
+        let raw = Address::from_word(word);
+        Ok(match chain_id {
+            ChainId::Tron => MultichainAddress::Tron { raw },
+            _ => MultichainAddress::from_raw(raw),
+        })
     }
 
     pub fn get_chain_id(&self) -> Option<ChainId> {
         match self {
             MultichainAddress::Ethereum { chain_id, .. } => *chain_id,
             MultichainAddress::Tron { .. } => Some(ChainId::Tron),
+            MultichainAddress::Solana { .. } => Some(ChainId::Solana),
         }
     }
 }
@@ -84,6 +137,7 @@ impl Display for MultichainAddress {
                 bytes.extend(&checksum[..4]);
                 bs58::encode(bytes).into_string()
             }
+            MultichainAddress::Solana { raw } => bs58::encode(raw).into_string(),
         };
         write!(f, "{str}")
     }
@@ -112,30 +166,47 @@ impl FromStr for MultichainAddress {
     type Err = crate::Error;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        if let Ok(eth_address) = Address::from_str(value) {
+        if let Some(eth_address) = parse_checksummed(value, None) {
             return Ok(MultichainAddress::Ethereum {
-                raw: eth_address,
+                raw: eth_address?,
                 chain_id: None,
             });
         } else if let Ok(bytes) = bs58::decode(&value).into_vec() {
-            if bytes.len() == 1 + 20 + 4 && bytes[0] == 0x41 {
+            if bytes.len() == 1 + 20 + 4
+                && bytes[0] == 0x41
+                && Sha256::digest(Sha256::digest(&bytes[..21]))[..4] == bytes[21..]
+            {
                 return Ok(MultichainAddress::Tron {
                     raw: Address::from_slice(&bytes[1..21]),
                 });
             }
-        } else if let Some(idx) = value.find("@") {
-            if let Some((left, right)) = value.split_at_checked(idx) {
-                let right = &right[1..]; // Skip '@'
-                let chain_id = ChainId::from_str(right)?;
-                if let Ok(address) = Address::from_str(left) {
-                    return Ok(match chain_id {
-                        ChainId::Tron => MultichainAddress::Tron { raw: address },
-                        _ => MultichainAddress::Ethereum {
-                            raw: address,
-                            chain_id: Some(chain_id),
-                        },
-                    });
-                }
+            if bytes.len() == 32 {
+                return Ok(MultichainAddress::Solana {
+                    raw: B256::from_slice(&bytes),
+                });
+            }
+        } else if let Some(idx) = value.find("@")
+            && let Some((left, right)) = value.split_at_checked(idx)
+        {
+            let right = &right[1..]; // Skip '@'
+            let chain_id = ChainId::from_str(right)?;
+            if chain_id == ChainId::Solana {
+                return match MultichainAddress::from_str(left)? {
+                    address @ MultichainAddress::Solana { .. } => Ok(address),
+                    _ => Err(crate::Error::MultichainAddressDecodeFailed(
+                        value.to_string(),
+                    )),
+                };
+            }
+            if let Some(address) = parse_checksummed(left, Some(chain_id)) {
+                let address = address?;
+                return Ok(match chain_id {
+                    ChainId::Tron => MultichainAddress::Tron { raw: address },
+                    _ => MultichainAddress::Ethereum {
+                        raw: address,
+                        chain_id: Some(chain_id),
+                    },
+                });
             }
         }
 
@@ -145,6 +216,29 @@ impl FromStr for MultichainAddress {
     }
 }
 
+// Hex addresses in mixed case must have a valid EIP-55 checksum, or an EIP-1191 one for
+// `chain_id` as written by `Display`. None if `value` is no hex address at all
+fn parse_checksummed(value: &str, chain_id: Option<ChainId>) -> Option<crate::Result<Address>> {
+    let address = Address::from_str(value).ok()?;
+    let hex = value.strip_prefix("0x").unwrap_or(value);
+
+    let is_mixed_case =
+        hex.chars().any(|c| c.is_ascii_lowercase()) && hex.chars().any(|c| c.is_ascii_uppercase());
+    let matches_checksum = |chain_id: Option<ChainId>| {
+        address.to_checksum(chain_id.map(|chain_id| chain_id as u64))[2..] == *hex
+    };
+    if is_mixed_case
+        && !matches_checksum(None)
+        && chain_id.is_none_or(|chain_id| !matches_checksum(Some(chain_id)))
+    {
+        return Some(Err(crate::Error::MultichainAddressDecodeFailed(format!(
+            "{value}: invalid checksum"
+        ))));
+    }
+
+    Some(Ok(address))
+}
+
 impl From<Address> for MultichainAddress {
     fn from(address: Address) -> Self {
         MultichainAddress::Ethereum {
@@ -160,7 +254,7 @@ mod test {
 
     use alloy::primitives::Address;
 
-    use crate::multichain_address::MultichainAddress;
+    use crate::{chain_id::ChainId, multichain_address::MultichainAddress};
 
     #[test]
     fn test_tron() {
@@ -195,4 +289,73 @@ mod test {
 
         assert_eq!(result.to_string(), "TJLRfJUAHPRxoizJeyYFFZ7nEHit4L9FfE");
     }
+
+    #[test]
+    fn test_checksum() {
+        let address = "0x5bc44f18b91f55540d11d612c08e4faad619eb55";
+        let checksummed = MultichainAddress::from_str(address).unwrap().to_string();
+        assert_eq!(
+            MultichainAddress::from_str(&checksummed).unwrap(),
+            MultichainAddress::from_str(address).unwrap()
+        );
+
+        let broken = checksummed.replacen('B', "b", 1);
+        assert!(matches!(
+            MultichainAddress::from_str(&broken),
+            Err(crate::Error::MultichainAddressDecodeFailed(_))
+        ));
+
+        // the chain suffix selects the EIP-1191 checksum of `Display`
+        let with_chain = MultichainAddress::Ethereum {
+            raw: address.parse().unwrap(),
+            chain_id: Some(ChainId::Arbitrum),
+        };
+        assert_eq!(
+            MultichainAddress::from_str(&with_chain.to_string()).unwrap(),
+            with_chain
+        );
+        assert!(MultichainAddress::from_str(&format!("{checksummed}@arb")).is_ok());
+        assert!(MultichainAddress::from_str(&format!("{broken}@arb")).is_err());
+    }
+
+    #[test]
+    fn test_solana() {
+        let address = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+        let result = MultichainAddress::from_str(address).unwrap();
+
+        assert!(matches!(result, MultichainAddress::Solana { .. }));
+        assert_eq!(result.to_string(), address);
+        assert_eq!(
+            MultichainAddress::from_str(&format!("{address}@solana")).unwrap(),
+            result
+        );
+        assert!(
+            MultichainAddress::from_str("0x5bc44f18b91f55540d11d612c08e4faad619eb55@solana")
+                .is_err()
+        );
+
+        let word = result.as_bytes32();
+        assert_eq!(word[12..], result.low_20_bytes()[..]);
+        assert_eq!(word[..12], result.first_part()[..]);
+        assert_eq!(
+            MultichainAddress::from_bytes32(ChainId::Solana, word).unwrap(),
+            result
+        );
+        assert!(MultichainAddress::from_bytes32(ChainId::Ethereum, word).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "use low_20_bytes")]
+    fn test_solana_as_raw() {
+        let address: MultichainAddress = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+            .parse()
+            .unwrap();
+        assert!(address.try_as_raw().is_err());
+        address.as_raw();
+    }
+
+    #[test]
+    fn test_try_from_tron_bad_checksum() {
+        assert!(MultichainAddress::from_str("TJLRfJUAHPRxoizJeyYFFZ7nEHit4L9FfF").is_err());
+    }
 }
@@ -0,0 +1,112 @@
+use alloy::{
+    network::TransactionBuilder,
+    primitives::{Address, U256},
+    providers::Provider,
+    rpc::types::TransactionRequest,
+    sol,
+    sol_types::SolCall,
+};
+
+use crate::{
+    addresses::{get_limit_order_contract_address, get_wrapped_native_address},
+    chain_id::ChainId,
+    utils::alloy::{ERC20, ensure_allowance},
+};
+
+// Limit orders can not spend the native currency, native source swaps are placed
+// with the wrapped token as maker asset, see `QuoteRequest::with_wrapped_native_src`
+sol! {
+    interface IWETH {
+        function deposit() external payable;
+        function withdraw(uint256 wad) external;
+    }
+}
+
+pub fn wrap_native_tx(chain_id: ChainId, amount: U256) -> TransactionRequest {
+    TransactionRequest::default()
+        .with_to(get_wrapped_native_address(chain_id).as_raw())
+        .with_value(amount)
+        .with_input(IWETH::depositCall {}.abi_encode())
+}
+
+pub fn unwrap_native_tx(chain_id: ChainId, amount: U256) -> TransactionRequest {
+    TransactionRequest::default()
+        .with_to(get_wrapped_native_address(chain_id).as_raw())
+        .with_input(IWETH::withdrawCall { wad: amount }.abi_encode())
+}
+
+// Wraps only the part of `amount` not already held as wrapped token
+pub async fn wrap_native(
+    provider: &impl Provider,
+    chain_id: ChainId,
+    owner: Address,
+    amount: U256,
+) -> crate::Result<()> {
+    let balance = ERC20::new(get_wrapped_native_address(chain_id).as_raw(), provider)
+        .balanceOf(owner)
+        .call()
+        .await
+        .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+    if balance >= amount {
+        return Ok(());
+    }
+
+    let receipt = provider
+        .send_transaction(wrap_native_tx(chain_id, amount - balance))
+        .await?
+        .get_receipt()
+        .await
+        .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+    if !receipt.status() {
+        return Err(crate::Error::InternalError(format!(
+            "wrap transaction {} reverted",
+            receipt.transaction_hash
+        )));
+    }
+
+    Ok(())
+}
+
+pub async fn wrap_and_approve(
+    provider: &impl Provider,
+    chain_id: ChainId,
+    owner: Address,
+    amount: U256,
+) -> crate::Result<()> {
+    wrap_native(provider, chain_id, owner, amount).await?;
+    ensure_allowance(
+        provider,
+        get_wrapped_native_address(chain_id).as_raw(),
+        owner,
+        get_limit_order_contract_address(chain_id).as_raw(),
+        amount,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    #[test]
+    fn test_wrap_native_tx() {
+        let tx = wrap_native_tx(ChainId::Arbitrum, U256::from(10).pow(U256::from(18)));
+
+        assert_eq!(
+            tx.to.unwrap().to(),
+            Some(&address!("0x82aF49447D8a07e3bd95BD0d56f35241523fBab1"))
+        );
+        assert_eq!(tx.value, Some(U256::from(10).pow(U256::from(18))));
+        assert_eq!(
+            tx.input.input().unwrap().as_ref(),
+            IWETH::depositCall::SELECTOR
+        );
+
+        let tx = unwrap_native_tx(ChainId::Arbitrum, U256::from(5));
+        let call = IWETH::withdrawCall::abi_decode(tx.input.input().unwrap()).unwrap();
+        assert_eq!(call.wad, U256::from(5));
+        assert!(tx.value.is_none());
+    }
+}
@@ -0,0 +1,417 @@
+use std::fmt::Display;
+
+use alloy::primitives::{B256, Bytes, FixedBytes, U256};
+use chrono::DateTime;
+use serde::Serialize;
+
+use crate::{
+    api::types::OrderType,
+    chain_id::ChainId,
+    constants::UINT_160_MAX,
+    escrow_extension::{EXTRA_DATA_BYTES_LENGTH, EscrowExtension},
+    fusion::{fusion_order::IntegratorFee, source_track::extract_track_code},
+    limit::{extension::Extension, maker_traits::MakerTraits},
+    multichain_address::MultichainAddress,
+    time_locks::TimeLocks,
+    utils::alloy::CustomAlloy,
+};
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderSummary {
+    pub order_hash: B256,
+    pub maker: MultichainAddress,
+    pub receiver: MultichainAddress,
+    pub src_chain_id: ChainId,
+    pub dst_chain_id: ChainId,
+    pub src_token: MultichainAddress,
+    pub dst_token: MultichainAddress,
+    pub making_amount: U256,
+    pub min_taking_amount: U256,
+    pub auction: AuctionSummary,
+    pub resolving_start_time: u64,
+    pub whitelist: Vec<WhitelistSummary>,
+    pub integrator_fee: Option<IntegratorFee>,
+    // Paid by the taker on top of `min_taking_amount`, which is net of the fee
+    pub integrator_fee_amount: Option<U256>,
+    pub bank_fee: Option<u64>,
+    pub maker_traits: MakerTraitsSummary,
+    pub time_locks: TimeLocks,
+    pub hash_lock: B256,
+    pub hash_lock_type: OrderType,
+    pub secrets_count: u64,
+    pub src_safety_deposit: U256,
+    pub dst_safety_deposit: U256,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuctionSummary {
+    pub start_time: u64,
+    pub end_time: u64,
+    pub initial_rate_bump: u64,
+    pub points: Vec<AuctionPointSummary>,
+    pub gas_bump_estimate: u64,
+    pub gas_price_estimate: U256,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuctionPointSummary {
+    pub timestamp: u64,
+    pub delay: u64,
+    pub coefficient: u64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WhitelistSummary {
+    pub address_half: FixedBytes<10>,
+    pub delay: u64,
+    pub allow_from: u64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MakerTraitsSummary {
+    pub nonce_or_epoch: u64,
+    pub series: u64,
+    pub expiration: u64,
+    pub allowed_sender: Option<FixedBytes<10>>,
+    pub allow_partial_fills: bool,
+    pub allow_multiple_fills: bool,
+    pub has_extension: bool,
+    pub has_pre_interaction: bool,
+    pub has_post_interaction: bool,
+    pub epoch_manager_enabled: bool,
+    pub permit2: bool,
+    pub native_unwrap: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaltSummary {
+    pub salt: U256,
+    pub extension_hash: FixedBytes<20>,
+    pub random: U256,
+    pub track_code: FixedBytes<4>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EscrowExtraDataSummary {
+    pub hash_lock: B256,
+    pub dst_chain_id: ChainId,
+    pub dst_token: MultichainAddress,
+    pub src_safety_deposit: U256,
+    pub dst_safety_deposit: U256,
+    pub time_locks: TimeLocks,
+}
+
+impl From<&MakerTraits> for MakerTraitsSummary {
+    fn from(maker_traits: &MakerTraits) -> Self {
+        Self {
+            nonce_or_epoch: maker_traits.nonce_or_epoch(),
+            series: maker_traits.series(),
+            expiration: maker_traits.expiration(),
+            allowed_sender: maker_traits
+                .is_private()
+                .then(|| maker_traits.allowed_sender()),
+            allow_partial_fills: maker_traits.is_partial_fill_allowed(),
+            allow_multiple_fills: maker_traits.is_multiple_fills_allowed(),
+            has_extension: maker_traits.has_extension(),
+            has_pre_interaction: maker_traits.has_pre_interaction(),
+            has_post_interaction: maker_traits.has_post_interaction(),
+            epoch_manager_enabled: maker_traits.is_epoch_manager_enabled(),
+            permit2: maker_traits.is_permit2(),
+            native_unwrap: maker_traits.is_native_unwrap_enabled(),
+        }
+    }
+}
+
+impl SaltSummary {
+    // Salt layout: track code (224..256), random (160..224), extension hash (0..160)
+    pub fn new(salt: U256) -> Self {
+        let extension_hash = salt & UINT_160_MAX;
+        let random = (salt >> 160) & U256::from(u64::MAX);
+
+        Self {
+            salt,
+            extension_hash: FixedBytes::from_slice(&extension_hash.to_be_bytes::<32>()[12..]),
+            random,
+            track_code: FixedBytes::from_slice(&extract_track_code(salt).to_be_bytes::<32>()[28..]),
+        }
+    }
+
+    pub fn matches_extension(&self, extension: &Extension) -> bool {
+        extension.keccak256().to_u256() & UINT_160_MAX == self.salt & UINT_160_MAX
+    }
+}
+
+impl EscrowExtraDataSummary {
+    // `bytes` is the extra data alone, use `from_extension` for a whole order extension
+    pub fn decode_from(bytes: Bytes) -> crate::Result<Self> {
+        if bytes.len() != EXTRA_DATA_BYTES_LENGTH {
+            return Err(crate::Error::Decode {
+                field: "extra_data",
+                offset: 0,
+                reason: format!(
+                    "expected {EXTRA_DATA_BYTES_LENGTH} bytes, got {}",
+                    bytes.len()
+                ),
+            });
+        }
+
+        let (
+            hash_lock,
+            dst_chain_id,
+            dst_token,
+            src_safety_deposit,
+            dst_safety_deposit,
+            time_locks,
+        ) = EscrowExtension::decode_extra_data(bytes)?;
+
+        Ok(Self {
+            hash_lock: hash_lock.value(),
+            dst_chain_id,
+            dst_token,
+            src_safety_deposit,
+            dst_safety_deposit,
+            time_locks,
+        })
+    }
+
+    // The extra data is the tail of the post interaction, which is only found after decoding
+    // the extension, custom data (e.g. of Solana receivers) follows it in the encoded bytes
+    pub fn from_extension(extension: Bytes) -> crate::Result<Self> {
+        Ok(Self::from(&EscrowExtension::decode_from(extension)?))
+    }
+}
+
+impl From<&EscrowExtension> for EscrowExtraDataSummary {
+    fn from(extension: &EscrowExtension) -> Self {
+        Self {
+            hash_lock: extension.hash_lock_info.value(),
+            dst_chain_id: extension.dst_chain_id,
+            dst_token: extension.dst_token,
+            src_safety_deposit: extension.src_safety_deposit,
+            dst_safety_deposit: extension.dst_safety_deposit,
+            time_locks: extension.time_locks.clone(),
+        }
+    }
+}
+
+fn format_timestamp(timestamp: u64) -> String {
+    DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|time| format!("{timestamp} ({})", time.to_rfc3339()))
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+impl Display for OrderSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Order {}", self.order_hash)?;
+        writeln!(f, "  maker:              {}", self.maker)?;
+        writeln!(f, "  receiver:           {}", self.receiver)?;
+        writeln!(
+            f,
+            "  route:              {} -> {}",
+            self.src_chain_id, self.dst_chain_id
+        )?;
+        writeln!(
+            f,
+            "  src token:          {} amount {}",
+            self.src_token, self.making_amount
+        )?;
+        writeln!(
+            f,
+            "  dst token:          {} min amount {}",
+            self.dst_token, self.min_taking_amount
+        )?;
+
+        writeln!(f, "Auction")?;
+        writeln!(
+            f,
+            "  start:              {}",
+            format_timestamp(self.auction.start_time)
+        )?;
+        writeln!(
+            f,
+            "  end:                {}",
+            format_timestamp(self.auction.end_time)
+        )?;
+        writeln!(
+            f,
+            "  initial rate bump:  {}",
+            self.auction.initial_rate_bump
+        )?;
+        for point in &self.auction.points {
+            writeln!(
+                f,
+                "  point:              {} (+{}s) coefficient {}",
+                format_timestamp(point.timestamp),
+                point.delay,
+                point.coefficient
+            )?;
+        }
+        writeln!(
+            f,
+            "  gas cost:           bump {} price {}",
+            self.auction.gas_bump_estimate, self.auction.gas_price_estimate
+        )?;
+
+        writeln!(f, "Resolvers")?;
+        writeln!(
+            f,
+            "  resolving start:    {}",
+            format_timestamp(self.resolving_start_time)
+        )?;
+        for item in &self.whitelist {
+            writeln!(
+                f,
+                "  ..{}:  +{}s from {}",
+                item.address_half,
+                item.delay,
+                format_timestamp(item.allow_from)
+            )?;
+        }
+        match &self.integrator_fee {
+            Some(fee) => writeln!(
+                f,
+                "  integrator fee:     {} to {}, {} on top of the min amount",
+                fee.ratio,
+                fee.receiver,
+                self.integrator_fee_amount.unwrap_or_default()
+            )?,
+            None => writeln!(f, "  integrator fee:     none")?,
+        }
+        match self.bank_fee {
+            Some(bank_fee) => writeln!(f, "  resolver fee:       {bank_fee}")?,
+            None => writeln!(f, "  resolver fee:       none")?,
+        }
+
+        writeln!(f, "{}", self.maker_traits)?;
+        writeln!(f, "{}", self.time_locks)?;
+        writeln!(
+            f,
+            "  safety deposit:     src {} dst {}",
+            self.src_safety_deposit, self.dst_safety_deposit
+        )?;
+
+        writeln!(f, "Hash lock")?;
+        writeln!(f, "  hash:               {}", self.hash_lock)?;
+        write!(
+            f,
+            "  type:               {:?} ({} secrets)",
+            self.hash_lock_type, self.secrets_count
+        )
+    }
+}
+
+impl Display for MakerTraitsSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Maker traits")?;
+        writeln!(f, "  nonce or epoch:     {}", self.nonce_or_epoch)?;
+        writeln!(f, "  series:             {}", self.series)?;
+        writeln!(
+            f,
+            "  expiration:         {}",
+            format_timestamp(self.expiration)
+        )?;
+        if let Some(allowed_sender) = self.allowed_sender {
+            writeln!(f, "  allowed sender:     ..{allowed_sender}")?;
+        }
+        writeln!(f, "  partial fills:      {}", self.allow_partial_fills)?;
+        writeln!(f, "  multiple fills:     {}", self.allow_multiple_fills)?;
+        writeln!(f, "  has extension:      {}", self.has_extension)?;
+        writeln!(f, "  pre interaction:    {}", self.has_pre_interaction)?;
+        writeln!(f, "  post interaction:   {}", self.has_post_interaction)?;
+        writeln!(f, "  epoch manager:      {}", self.epoch_manager_enabled)?;
+        writeln!(f, "  permit2:            {}", self.permit2)?;
+        write!(f, "  native unwrap:      {}", self.native_unwrap)
+    }
+}
+
+impl Display for SaltSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Salt {:#x}", self.salt)?;
+        writeln!(f, "  extension hash:     {}", self.extension_hash)?;
+        writeln!(f, "  random:             {:#x}", self.random)?;
+        write!(f, "  track code:         {}", self.track_code)
+    }
+}
+
+impl Display for EscrowExtraDataSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Escrow extra data")?;
+        writeln!(f, "  hash lock:          {}", self.hash_lock)?;
+        writeln!(f, "  dst chain:          {}", self.dst_chain_id)?;
+        writeln!(f, "  dst token:          {}", self.dst_token)?;
+        writeln!(
+            f,
+            "  safety deposit:     src {} dst {}",
+            self.src_safety_deposit, self.dst_safety_deposit
+        )?;
+        write!(f, "{}", self.time_locks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{limit::extension_builder::ExtensionBuildable, test_utils::active_order_fixture};
+
+    #[test]
+    fn test_decode_raw_values() {
+        let order = active_order_fixture();
+        let extension = Extension::decode_from(order.extension.clone()).unwrap();
+
+        let salt = SaltSummary::new(order.order.salt);
+        assert!(salt.matches_extension(&extension));
+        assert_eq!(salt.extension_hash.as_slice(), &extension.keccak256()[12..]);
+        assert!(!SaltSummary::new(order.order.salt + U256::ONE).matches_extension(&extension));
+
+        let traits = MakerTraitsSummary::from(&MakerTraits::new(order.order.makerTraits));
+        assert!(traits.has_extension);
+        assert!(traits.has_post_interaction);
+        assert!(!traits.allow_multiple_fills);
+        assert!(traits.to_string().contains("post interaction:   true"));
+
+        let extra_data = EscrowExtraDataSummary::from_extension(order.extension.clone()).unwrap();
+        assert_eq!(extra_data.dst_chain_id, ChainId::Optimism);
+        assert_eq!(extra_data.time_locks.dst_cancellation(), 480);
+        assert_eq!(
+            TimeLocks::from_u256(extra_data.time_locks.build()).to_string(),
+            extra_data.time_locks.to_string()
+        );
+    }
+
+    #[test]
+    fn test_decode_extra_data_with_custom_data() {
+        let order = active_order_fixture();
+        let mut escrow_extension = EscrowExtension::decode_from(order.extension).unwrap();
+        escrow_extension.dst_address_first_part = FixedBytes::repeat_byte(0x11);
+        let extension = escrow_extension.build();
+        assert!(!extension.custom_data.is_empty());
+
+        let extra_data = EscrowExtraDataSummary::from_extension(extension.encode()).unwrap();
+        assert_eq!(
+            extra_data.hash_lock,
+            escrow_extension.hash_lock_info.value()
+        );
+        assert_eq!(extra_data.dst_token, escrow_extension.dst_token);
+        assert_eq!(extra_data.time_locks, escrow_extension.time_locks);
+
+        let raw =
+            EscrowExtraDataSummary::decode_from(escrow_extension.encode_extra_data()).unwrap();
+        assert_eq!(raw.hash_lock, extra_data.hash_lock);
+
+        // the tail of the extension is custom data, not extra data
+        assert!(matches!(
+            EscrowExtraDataSummary::decode_from(extension.encode()),
+            Err(crate::Error::Decode {
+                field: "extra_data",
+                ..
+            })
+        ));
+    }
+}
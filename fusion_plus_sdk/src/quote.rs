@@ -4,11 +4,14 @@ use alloy::primitives::{Bytes, U256};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    addresses::get_wrapped_native_address,
     chain_id::ChainId,
+    constants::NATIVE_CURRENCY,
     fusion::auction_details::AuctionWhitelistItem,
     multichain_address::MultichainAddress,
     quote::preset::{Preset, PresetType},
     time_locks::TimeLocks,
+    tokens::TokenAmount,
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -104,6 +107,41 @@ impl QuoteRequest {
             is_permit2: None,
         }
     }
+
+    // Quotes `amount` of its token on its chain, so chain, token and decimals can not disagree
+    pub fn from_token_amount(
+        amount: TokenAmount,
+        dst_chain_id: impl Into<ChainId>,
+        dst_token_address: impl Into<MultichainAddress>,
+        enable_estimate: bool,
+        maker_address: impl Into<MultichainAddress>,
+    ) -> Self {
+        Self::new(
+            amount.token.chain_id,
+            dst_chain_id,
+            amount.token.address,
+            dst_token_address,
+            amount.amount,
+            enable_estimate,
+            maker_address,
+        )
+    }
+
+    // Integrator fee in bps, the quoted amounts are net of it. Pass the same fee to
+    // `CrossChainOrderParams::fee` when building the order
+    pub fn with_fee(mut self, fee_bps: u16) -> Self {
+        self.fee = (fee_bps > 0).then_some(fee_bps as u64);
+        self
+    }
+
+    // Limit orders can not spend the native currency, quote the wrapped token instead
+    // and wrap before placing the order, see `native::wrap_and_approve`
+    pub fn with_wrapped_native_src(mut self) -> Self {
+        if self.src_token_address == NATIVE_CURRENCY {
+            self.src_token_address = get_wrapped_native_address(self.src_chain_id);
+        }
+        self
+    }
 }
 
 impl QuoteResult {
@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use alloy::primitives::U256;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
@@ -17,6 +19,20 @@ pub enum PresetType {
     Custom,
 }
 
+impl FromStr for PresetType {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fast" => Ok(PresetType::Fast),
+            "medium" => Ok(PresetType::Medium),
+            "slow" => Ok(PresetType::Slow),
+            "custom" => Ok(PresetType::Custom),
+            _ => Err(crate::Error::InternalError(format!("unknown preset {s}"))),
+        }
+    }
+}
+
 // https://github.com/1inch/cross-chain-sdk/blob/25ac3927c706a43e85f2f08cc9d9a3bdf156e1e9/src/api/quoter/preset.ts#L4
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
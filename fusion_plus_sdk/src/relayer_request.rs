@@ -1,13 +1,14 @@
-use alloy::{
-    primitives::{B256, Bytes},
-    signers::Signature,
-};
+use alloy::primitives::{B256, Bytes};
 use serde::Serialize;
 
 use crate::{
     chain_id::ChainId,
     cross_chain_order::PreparedOrder,
-    limit::{eip712::LimitOrderV4, extension_builder::ExtensionBuildable},
+    limit::{
+        eip712::LimitOrderV4,
+        extension_builder::ExtensionBuildable,
+        signature::{OrderSignature, verify_eoa_signature},
+    },
 };
 
 #[derive(Debug, Clone, Serialize)]
@@ -22,33 +23,155 @@ pub struct RelayerRequest {
 }
 
 impl RelayerRequest {
+    // Contract signatures are accepted as is, they can only be checked on chain with
+    // `OrderSignature::verify`
+    pub fn try_from_prepared_order(
+        prepared_order: &PreparedOrder,
+        signature: &OrderSignature,
+    ) -> crate::Result<Self> {
+        let order = prepared_order.to_v4();
+
+        if let OrderSignature::Eoa(signature) = signature {
+            verify_eoa_signature(
+                signature,
+                order.maker,
+                prepared_order.eip712_signing_hash()?,
+            )?;
+        }
+
+        // the relayer expects hashes only for orders with multiple fills
+        let secret_hashes =
+            (prepared_order.secret_hashes.len() > 1).then(|| prepared_order.secret_hashes.clone());
+
+        Ok(RelayerRequest {
+            src_chain_id: prepared_order.src_chain_id,
+            order,
+            signature: signature.to_bytes(),
+            quote_id: prepared_order.quote_id.clone(),
+            extension: prepared_order.order.inner.extension.build().encode(),
+            secret_hashes,
+        })
+    }
+
+    #[deprecated(
+        note = "use try_from_prepared_order, it takes the quote id and secret hashes from the order"
+    )]
     pub fn from_prepared_order(
         prepared_order: &PreparedOrder,
-        signature: &Signature,
+        signature: &OrderSignature,
         quote_id: String,
         secret_hashes: Option<Vec<B256>>,
     ) -> Self {
-        let order = prepared_order.to_v4();
-
-        assert_eq!(
-            signature
-                .recover_address_from_prehash(&order.get_order_hash(prepared_order.src_chain_id))
-                .unwrap(),
-            order.maker,
-            "Signature does not match order maker"
-        );
+        let request = Self::try_from_prepared_order(prepared_order, signature)
+            .expect("Signature does not match order maker");
 
         RelayerRequest {
-            src_chain_id: prepared_order.src_chain_id,
-            order,
-            signature: signature.as_bytes().into(),
             quote_id,
-            extension: prepared_order.order.inner.extension.build().encode(),
             secret_hashes,
+            ..request
         }
     }
 
-    pub fn order_hash(&self) -> B256 {
+    pub fn order_hash(&self) -> crate::Result<B256> {
         self.order.get_order_hash(self.src_chain_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        primitives::{B256, Bytes},
+        signers::local::PrivateKeySigner,
+    };
+
+    use super::*;
+    use crate::{
+        cross_chain_order::CrossChainOrderParams,
+        hash_lock::HashLock,
+        quote::{QuoteRequest, preset::PresetType},
+        test_utils::quote_fixture,
+    };
+
+    fn prepared_order(signer: &PrivateKeySigner, preset: PresetType) -> PreparedOrder {
+        let (request, result) = quote_fixture();
+        let request = QuoteRequest {
+            maker_address: signer.address().into(),
+            ..request
+        };
+        let secrets: Vec<B256> = (0..result.get_preset(preset).unwrap().secrets_count)
+            .map(|i| B256::repeat_byte(i as u8 + 1))
+            .collect();
+
+        PreparedOrder::from_quote(
+            &request,
+            &result,
+            CrossChainOrderParams {
+                dst_address: request.maker_address,
+                hash_lock: HashLock::from_secrets(&secrets).unwrap(),
+                secret_hashes: secrets.iter().map(HashLock::hash_secret).collect(),
+                fee: None,
+                resolver_fee: None,
+                preset: Some(preset),
+                epoch: None,
+                permit: None,
+            },
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_try_from_prepared_order() {
+        let signer = PrivateKeySigner::random();
+
+        let order = prepared_order(&signer, PresetType::Fast);
+        let signature = order.sign(&signer).await.unwrap();
+        let request = RelayerRequest::try_from_prepared_order(&order, &signature).unwrap();
+        assert_eq!(request.quote_id, order.quote_id);
+        assert_eq!(request.order_hash().unwrap(), order.hash);
+        assert_eq!(request.signature, signature.to_bytes());
+        assert_eq!(request.secret_hashes.unwrap().len(), 4);
+
+        let order = prepared_order(&signer, PresetType::Slow);
+        let signature = order.sign(&signer).await.unwrap();
+        let request = RelayerRequest::try_from_prepared_order(&order, &signature).unwrap();
+        assert_eq!(request.secret_hashes, None);
+
+        // signature of another order
+        let other = prepared_order(&signer, PresetType::Fast);
+        assert!(matches!(
+            RelayerRequest::try_from_prepared_order(&other, &signature),
+            Err(crate::Error::InvalidSignature(_))
+        ));
+
+        // contract makers validate the signature themselves
+        let signature = OrderSignature::Contract(Bytes::from_static(&[1, 2, 3]));
+        let request = RelayerRequest::try_from_prepared_order(&order, &signature).unwrap();
+        assert_eq!(request.signature, Bytes::from_static(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn test_mismatched_secret_hashes() {
+        let (request, result) = quote_fixture();
+        let secrets = [
+            B256::repeat_byte(1),
+            B256::repeat_byte(2),
+            B256::repeat_byte(3),
+        ];
+
+        let order = PreparedOrder::from_quote(
+            &request,
+            &result,
+            CrossChainOrderParams {
+                dst_address: request.maker_address,
+                hash_lock: HashLock::from_secrets(&secrets).unwrap(),
+                secret_hashes: secrets[..2].iter().map(HashLock::hash_secret).collect(),
+                fee: None,
+                resolver_fee: None,
+                preset: Some(PresetType::Fast),
+                epoch: None,
+                permit: None,
+            },
+        );
+        assert!(matches!(order, Err(crate::Error::InvalidSecretHashes(_))));
+    }
+}
@@ -0,0 +1,431 @@
+use alloy::{
+    primitives::{Address, Bytes, U256},
+    providers::Provider,
+    sol,
+    sol_types::{SolError, SolValue},
+};
+
+use crate::{
+    addresses::{try_get_limit_order_contract_address, try_get_permit2_contract_address},
+    api::types::ValidationStatus,
+    cross_chain_order::PreparedOrder,
+    hash_lock::HashLock,
+    limit::{
+        eip712::LimitOrderV4,
+        extension_builder::ExtensionBuildable,
+        interaction::Interaction,
+        maker_traits::MakerTraits,
+        signature::{OrderSignature, is_valid_contract_signature, verify_eoa_signature},
+    },
+    utils::alloy::ERC20,
+};
+
+sol! {
+    #[sol(rpc)]
+    interface IOrderMixin {
+        struct Order {
+            uint256 salt;
+            address maker;
+            address receiver;
+            address makerAsset;
+            address takerAsset;
+            uint256 makingAmount;
+            uint256 takingAmount;
+            uint256 makerTraits;
+        }
+
+        error BadSignature();
+        error PredicateIsNotTrue();
+        error TransferFromMakerToTakerFailed();
+        error EpochManagerAndBitInvalidatorsAreIncompatible();
+
+        function checkPredicate(bytes calldata predicate) external view returns (bool);
+        function fillOrderArgs(Order calldata order, bytes32 r, bytes32 vs, uint256 amount, uint256 takerTraits, bytes calldata args) external payable returns (uint256, uint256, bytes32);
+        function fillContractOrderArgs(Order calldata order, bytes calldata signature, uint256 amount, uint256 takerTraits, bytes calldata args) external returns (uint256, uint256, bytes32);
+    }
+
+    interface IBaseEscrowFactory {
+        error InsufficientEscrowBalance();
+        error InvalidPartialFill();
+        error InvalidSecretsAmount();
+        error InvalidProof();
+    }
+}
+
+// https://github.com/1inch/limit-order-protocol/blob/master/contracts/libraries/TakerTraitsLib.sol
+const TAKER_MAKER_AMOUNT_FLAG: U256 = U256::from_limbs([0, 0, 0, 1 << 63]);
+const TAKER_ARGS_EXTENSION_LENGTH_OFFSET: usize = 224;
+const TAKER_ARGS_INTERACTION_LENGTH_OFFSET: usize = 200;
+
+impl From<LimitOrderV4> for IOrderMixin::Order {
+    fn from(order: LimitOrderV4) -> Self {
+        Self {
+            salt: order.salt,
+            maker: order.maker,
+            receiver: order.receiver,
+            makerAsset: order.makerAsset,
+            takerAsset: order.takerAsset,
+            makingAmount: order.makingAmount,
+            takingAmount: order.takingAmount,
+            makerTraits: order.makerTraits,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderSimulation {
+    pub status: ValidationStatus,
+    // Raw revert data of the test fill, set when it reverted
+    pub revert_data: Option<Bytes>,
+}
+
+impl OrderSimulation {
+    fn status(status: ValidationStatus) -> Self {
+        Self {
+            status,
+            revert_data: None,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.status == ValidationStatus::Valid
+    }
+}
+
+// Runs the checks of the relayer with `eth_call`s against `provider`, which can be a
+// local anvil fork of the source chain. `resolver` must be a whitelisted resolver of the
+// order (see `QuoteResult::whitelist`), it is the sender of the test fill. The test fill
+// can not fund the source escrow, so its `InsufficientEscrowBalance` revert means the limit
+// order protocol and the escrow factory accepted the order
+pub async fn simulate_order(
+    provider: &impl Provider,
+    prepared_order: &PreparedOrder,
+    signature: &OrderSignature,
+    resolver: Address,
+) -> crate::Result<OrderSimulation> {
+    let chain_id = prepared_order.src_chain_id;
+    let limit_order_protocol = try_get_limit_order_contract_address(chain_id)?.as_raw();
+    let order = prepared_order.to_v4();
+    let extension = prepared_order.order.inner.extension.build();
+    let maker_traits = MakerTraits::new(order.makerTraits);
+
+    let is_signature_valid = match signature {
+        OrderSignature::Eoa(signature) => {
+            verify_eoa_signature(signature, order.maker, prepared_order.hash).is_ok()
+        }
+        OrderSignature::Contract(signature) => {
+            is_valid_contract_signature(
+                provider,
+                order.maker,
+                prepared_order.hash,
+                signature.clone(),
+            )
+            .await?
+        }
+    };
+    if !is_signature_valid {
+        return Ok(OrderSimulation::status(ValidationStatus::InvalidSignature));
+    }
+
+    let maker_asset = ERC20::new(order.makerAsset, provider);
+    let balance = maker_asset
+        .balanceOf(order.maker)
+        .call()
+        .await
+        .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+    if balance < order.makingAmount {
+        return Ok(OrderSimulation::status(ValidationStatus::NotEnoughBalance));
+    }
+
+    // a permit in the extension grants the allowance during the fill
+    if extension.maker_permit.is_empty() {
+        let spender = if maker_traits.is_permit2() {
+            try_get_permit2_contract_address(chain_id)?.as_raw()
+        } else {
+            limit_order_protocol
+        };
+        let allowance = maker_asset
+            .allowance(order.maker, spender)
+            .call()
+            .await
+            .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+        if allowance < order.makingAmount {
+            return Ok(OrderSimulation::status(
+                ValidationStatus::NotEnoughAllowance,
+            ));
+        }
+    }
+
+    let protocol = IOrderMixin::new(limit_order_protocol, provider);
+    if !extension.predicate.is_empty() {
+        let holds = protocol
+            .checkPredicate(extension.predicate.clone())
+            .call()
+            .await
+            .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+        if !holds {
+            return Ok(OrderSimulation::status(
+                ValidationStatus::OrderPredicateReturnedFalse,
+            ));
+        }
+    }
+
+    let (taker_traits, args) = full_fill_args(prepared_order)?;
+    let fill = match signature {
+        OrderSignature::Eoa(signature) => {
+            let compact = signature.as_erc2098();
+            protocol
+                .fillOrderArgs(
+                    order.clone().into(),
+                    compact[..32].try_into().unwrap(),
+                    compact[32..].try_into().unwrap(),
+                    order.makingAmount,
+                    taker_traits,
+                    args,
+                )
+                .from(resolver)
+                .call()
+                .await
+                .map(|_| ())
+        }
+        OrderSignature::Contract(signature) => protocol
+            .fillContractOrderArgs(
+                order.clone().into(),
+                signature.clone(),
+                order.makingAmount,
+                taker_traits,
+                args,
+            )
+            .from(resolver)
+            .call()
+            .await
+            .map(|_| ()),
+    };
+
+    let revert_data = match fill {
+        Ok(()) => return Ok(OrderSimulation::status(ValidationStatus::Valid)),
+        Err(e) => e
+            .as_revert_data()
+            .ok_or_else(|| crate::Error::InternalError(e.to_string()))?,
+    };
+
+    Ok(OrderSimulation {
+        status: fill_revert_status(&revert_data),
+        revert_data: Some(revert_data),
+    })
+}
+
+// Taker traits and args filling the whole order. The escrow factory takes the hash lock of
+// an order with multiple fills from the secret validated by its taker interaction, a full fill
+// has to use the last secret
+fn full_fill_args(prepared_order: &PreparedOrder) -> crate::Result<(U256, Bytes)> {
+    let extension = prepared_order.order.inner.extension.build().encode();
+    let maker_traits = &prepared_order.order.inner.inner.maker_traits;
+    let secret_hashes = &prepared_order.secret_hashes;
+
+    let interaction = if maker_traits.is_multiple_fills_allowed() && secret_hashes.len() > 1 {
+        let leaves = HashLock::get_merkle_leaves_by_secret_hashes(secret_hashes);
+        let idx = secret_hashes.len() - 1;
+        Interaction {
+            target: prepared_order.order.inner.settlement_extension_contract,
+            data: (
+                HashLock::get_proof(&leaves, idx)?,
+                U256::from(idx),
+                secret_hashes[idx],
+            )
+                .abi_encode_params()
+                .into(),
+        }
+        .encode()
+    } else {
+        Bytes::new()
+    };
+
+    let taker_traits = TAKER_MAKER_AMOUNT_FLAG
+        | (U256::from(extension.len()) << TAKER_ARGS_EXTENSION_LENGTH_OFFSET)
+        | (U256::from(interaction.len()) << TAKER_ARGS_INTERACTION_LENGTH_OFFSET);
+
+    Ok((taker_traits, [extension, interaction].concat().into()))
+}
+
+fn fill_revert_status(revert_data: &Bytes) -> ValidationStatus {
+    let Some(selector) = revert_data.get(..4) else {
+        return ValidationStatus::UnknownFailure;
+    };
+
+    match <[u8; 4]>::try_from(selector).unwrap() {
+        IBaseEscrowFactory::InsufficientEscrowBalance::SELECTOR => ValidationStatus::Valid,
+        IOrderMixin::BadSignature::SELECTOR => ValidationStatus::InvalidSignature,
+        IOrderMixin::PredicateIsNotTrue::SELECTOR => ValidationStatus::OrderPredicateReturnedFalse,
+        // balance is checked before the fill, so the transfer fails on the allowance
+        IOrderMixin::TransferFromMakerToTakerFailed::SELECTOR => {
+            ValidationStatus::NotEnoughAllowance
+        }
+        IOrderMixin::EpochManagerAndBitInvalidatorsAreIncompatible::SELECTOR => {
+            ValidationStatus::WrongEpochManagerAndBitInvalidator
+        }
+        _ => ValidationStatus::UnknownFailure,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        primitives::B256,
+        providers::ProviderBuilder,
+        signers::{SignerSync, local::PrivateKeySigner},
+        transports::mock::Asserter,
+    };
+
+    use super::*;
+    use crate::{
+        cross_chain_order::CrossChainOrderParams,
+        hash_lock::HashLock,
+        quote::{QuoteRequest, preset::PresetType},
+        test_utils::quote_fixture,
+    };
+
+    fn push_revert(asserter: &Asserter, error: impl SolError) {
+        asserter.push_failure(
+            serde_json::from_value(serde_json::json!({
+                "code": 3,
+                "message": "execution reverted",
+                "data": Bytes::from(error.abi_encode()),
+            }))
+            .unwrap(),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simulate_order() {
+        let signer = PrivateKeySigner::random();
+        let (request, result) = quote_fixture();
+        let request = QuoteRequest {
+            maker_address: signer.address().into(),
+            ..request
+        };
+        let secret = B256::repeat_byte(1);
+        let order = PreparedOrder::from_quote(
+            &request,
+            &result,
+            CrossChainOrderParams {
+                dst_address: request.maker_address,
+                hash_lock: HashLock::for_single_fill(&secret),
+                secret_hashes: vec![HashLock::hash_secret(&secret)],
+                fee: None,
+                resolver_fee: None,
+                preset: Some(PresetType::Slow),
+                epoch: None,
+                permit: None,
+            },
+        )
+        .unwrap();
+        let signature = order.sign(&signer).await.unwrap();
+        let resolver = result.whitelist[0].as_raw();
+        let amount = Bytes::from(request.src_amount.to_be_bytes::<32>());
+
+        let asserter = Asserter::new();
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+
+        // the fill reaches the escrow factory, which is not funded by the test fill
+        asserter.push_success(&amount);
+        asserter.push_success(&amount);
+        push_revert(&asserter, IBaseEscrowFactory::InsufficientEscrowBalance {});
+        let simulation = simulate_order(&provider, &order, &signature, resolver)
+            .await
+            .unwrap();
+        assert!(simulation.is_valid());
+        assert!(simulation.revert_data.is_some());
+
+        asserter.push_success(&amount);
+        asserter.push_success(&Bytes::from(U256::ZERO.to_be_bytes::<32>()));
+        let simulation = simulate_order(&provider, &order, &signature, resolver)
+            .await
+            .unwrap();
+        assert_eq!(simulation.status, ValidationStatus::NotEnoughAllowance);
+
+        asserter.push_success(&amount);
+        asserter.push_success(&amount);
+        push_revert(&asserter, IOrderMixin::PredicateIsNotTrue {});
+        let simulation = simulate_order(&provider, &order, &signature, resolver)
+            .await
+            .unwrap();
+        assert_eq!(
+            simulation.status,
+            ValidationStatus::OrderPredicateReturnedFalse
+        );
+
+        // signed by someone else, no calls are made
+        let other = OrderSignature::Eoa(
+            PrivateKeySigner::random()
+                .sign_hash_sync(&order.hash)
+                .unwrap(),
+        );
+        let simulation = simulate_order(&provider, &order, &other, resolver)
+            .await
+            .unwrap();
+        assert_eq!(simulation.status, ValidationStatus::InvalidSignature);
+    }
+
+    #[test]
+    fn test_full_fill_args() {
+        let (request, result) = quote_fixture();
+        let secret_hashes: Vec<B256> = (1..=4)
+            .map(|byte| HashLock::hash_secret(&B256::repeat_byte(byte)))
+            .collect();
+        let hash_lock = HashLock::from_secret_hashes(&secret_hashes).unwrap();
+        let order = PreparedOrder::from_quote(
+            &request,
+            &result,
+            CrossChainOrderParams {
+                dst_address: request.maker_address,
+                hash_lock: hash_lock.clone(),
+                secret_hashes: secret_hashes.clone(),
+                fee: None,
+                resolver_fee: None,
+                preset: Some(PresetType::Fast),
+                epoch: None,
+                permit: None,
+            },
+        )
+        .unwrap();
+
+        let (taker_traits, args) = full_fill_args(&order).unwrap();
+        let extension_len =
+            (taker_traits >> TAKER_ARGS_EXTENSION_LENGTH_OFFSET).to::<usize>() & 0xffffff;
+        let interaction_len =
+            (taker_traits >> TAKER_ARGS_INTERACTION_LENGTH_OFFSET).to::<usize>() & 0xffffff;
+        assert_eq!(extension_len + interaction_len, args.len());
+        assert_eq!(
+            args[..extension_len],
+            order.order.inner.extension.build().encode()[..]
+        );
+
+        // the last secret completes the fill, its proof has to match the hash lock
+        let interaction = &args[extension_len..];
+        assert_eq!(interaction[..20], result.src_escrow_factory.as_raw()[..]);
+        let (proof, idx, secret_hash) =
+            <(Vec<B256>, U256, B256)>::abi_decode_params(&interaction[20..]).unwrap();
+        assert_eq!(idx, U256::from(3));
+        assert_eq!(secret_hash, secret_hashes[3]);
+        let leaves = HashLock::get_merkle_leaves_by_secret_hashes(&secret_hashes);
+        assert_eq!(proof, HashLock::get_proof(&leaves, 3).unwrap());
+
+        assert_eq!(
+            fill_revert_status(
+                &IBaseEscrowFactory::InvalidPartialFill {}
+                    .abi_encode()
+                    .into()
+            ),
+            ValidationStatus::UnknownFailure
+        );
+        assert_eq!(
+            fill_revert_status(
+                &IBaseEscrowFactory::InvalidSecretsAmount {}
+                    .abi_encode()
+                    .into()
+            ),
+            ValidationStatus::UnknownFailure
+        );
+    }
+}
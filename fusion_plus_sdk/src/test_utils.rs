@@ -0,0 +1,111 @@
+use alloy::primitives::U256;
+
+use crate::{
+    api::types::ActiveOrder,
+    chain_id::ChainId,
+    multichain_address::MultichainAddress,
+    quote::{QuoteRequest, QuoteResult},
+};
+
+// Arbitrum -> Optimism USDC order as returned by `Api::get_active_orders`
+pub fn active_order_fixture() -> ActiveOrder {
+    serde_json::from_value(serde_json::json!({
+        "quoteId": "b8df822c-6dc2-4a75-ac2a-fa9269174cab",
+        "orderHash": "0x5e30e2d3db444542c04a3074c2eb68801c5d21a4bb624e7af60647fe0d08c60f",
+        "signature": "0x435c220f9031887cca799f18c39f1e63ee9dedb2d9daa869c784443c5d9386c747eec1a0cc9bf8f623a03a35555665d8398f49537cc2a48dd03d5cee55a7d7fb1b",
+        "deadline": "+011974-06-29T03:30:12.000Z",
+        "auctionStartDate": "2025-07-31T08:41:10.000Z",
+        "auctionEndDate": "2025-07-31T08:44:10.000Z",
+        "remainingMakerAmount": "1000000",
+        "makerBalance": "7000000",
+        "makerAllowance": "115792089237316195423570985008687907853269984665640564039457584007913116639935",
+        "order": {
+            "salt": "59760570321745959693867245748379178318940997668073785827538330995644313864705",
+            "maker": "0x5bc44f18b91f55540d11d612c08e4faad619eb55",
+            "receiver": "0x0000000000000000000000000000000000000000",
+            "makerAsset": "0xaf88d065e77c8cc2239327c5edb3a432268e5831",
+            "takerAsset": "0xda0000d4000015a526378bb6fafc650cea5966f8",
+            "makingAmount": "1000000",
+            "takingAmount": "915736",
+            "makerTraits": "62419173104490761595518734107435110408028858411109332347501650304779241390080"
+        },
+        "extension": "0x0000013b0000005e0000005e0000005e0000005e0000002f0000000000000000a7bcb4eac8964306f9e3764f67db6a7af6ddf99a055df00000000a688b2c260000b40a9f5607b40f0078055df0003ca7bcb4eac8964306f9e3764f67db6a7af6ddf99a055df00000000a688b2c260000b40a9f5607b40f0078055df0003ca7bcb4eac8964306f9e3764f67db6a7af6ddf99a688b2c15f0fc4b2dbe711e518acb000072f8a0c8c415454f629c00005ba74b09ae44e823cf770000187ce799a15840fbd2a45481a80d4755b0fb99107f03199655de7732abe605098a000000000000000000000000000000000000000000000000000000000000000a0000000000000000000000000b2c639c533813f4aa9d7837caf62653d097ff850000000000000000000004639818420000000000000000000000006e0c55012000000000000001e0000001680000003c000002b800000240000001a40000003c",
+        "srcChainId": 42161,
+        "dstChainId": 10,
+        "isMakerContract": false,
+        "secretHashes": null,
+        "fills": []
+    }))
+    .unwrap()
+}
+
+// Arbitrum -> Optimism USDC quote, `fast` allows partial and multiple fills, `slow` allows neither
+pub fn quote_fixture() -> (QuoteRequest, QuoteResult) {
+    let request = QuoteRequest::new(
+        ChainId::Arbitrum,
+        ChainId::Optimism,
+        "0xaf88d065e77c8cc2239327c5edb3a432268e5831"
+            .parse::<MultichainAddress>()
+            .unwrap(),
+        "0x0b2c639c533813f4aa9d7837caf62653d097ff85"
+            .parse::<MultichainAddress>()
+            .unwrap(),
+        U256::from(10_000_000),
+        true,
+        "0x5bc44f18b91f55540d11d612c08e4faad619eb55"
+            .parse::<MultichainAddress>()
+            .unwrap(),
+    );
+
+    let preset = |partial_fills: bool, secrets_count: usize| {
+        serde_json::json!({
+            "auctionDuration": 180,
+            "startAuctionIn": 24,
+            "initialRateBump": 84909,
+            "auctionStartAmount": "9985500",
+            "startAmount": "9915736",
+            "auctionEndAmount": "9900000",
+            "exclusiveResolver": null,
+            "costInDstToken": "69764",
+            "points": [{ "delay": 120, "coefficient": 63932 }],
+            "allowPartialFills": partial_fills,
+            "allowMultipleFills": partial_fills,
+            "gasCost": { "gasBumpEstimate": 63932, "gasPriceEstimate": "1051" },
+            "secretsCount": secrets_count
+        })
+    };
+
+    let result = serde_json::from_value(serde_json::json!({
+        "quoteId": "b8df822c-6dc2-4a75-ac2a-fa9269174cab",
+        "srcTokenAmount": "10000000",
+        "dstTokenAmount": "9915736",
+        "presets": {
+            "fast": preset(true, 4),
+            "medium": preset(true, 4),
+            "slow": preset(false, 1)
+        },
+        "srcEscrowFactory": "0xa7bcb4eac8964306f9e3764f67db6a7af6ddf99a",
+        "dstEscrowFactory": "0xa7bcb4eac8964306f9e3764f67db6a7af6ddf99a",
+        "whitelist": [
+            "0x33b41fe18d3a39046ad672f8a0c8c415454f629c",
+            "0xcfa62f77920d6383be12c91c71bd403599e1116f"
+        ],
+        "timeLocks": {
+            "srcWithdrawal": 60,
+            "srcPublicWithdrawal": 420,
+            "srcCancellation": 576,
+            "srcPublicCancellation": 696,
+            "dstWithdrawal": 60,
+            "dstPublicWithdrawal": 360,
+            "dstCancellation": 480
+        },
+        "srcSafetyDeposit": "1149000000000",
+        "dstSafetyDeposit": "132000000000",
+        "recommendedPreset": "fast",
+        "prices": { "usd": { "srcToken": "0.9998", "dstToken": "0.9999" } },
+        "volume": { "usd": { "srcToken": "10", "dstToken": "9.91" } }
+    }))
+    .unwrap();
+
+    (request, result)
+}
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use alloy::primitives::U256;
 use serde::{Deserialize, Serialize};
 
@@ -30,24 +32,32 @@ impl TimeLocks {
         dst_cancellation: u64,
         deployed_at: Option<u64>,
     ) -> Self {
-        let deployed_at = deployed_at.unwrap_or(0);
-
-        assert!(deployed_at <= UINT_32_MAX);
-        assert!(src_withdrawal <= UINT_32_MAX);
-        assert!(src_withdrawal < src_public_withdrawal);
-        assert!(src_public_withdrawal <= UINT_32_MAX);
-        assert!(src_public_withdrawal < src_cancellation);
-        assert!(src_cancellation <= UINT_32_MAX);
-        assert!(src_cancellation < src_public_cancellation);
-        assert!(src_public_cancellation <= UINT_32_MAX);
-        assert!(dst_withdrawal <= UINT_32_MAX);
-        assert!(dst_withdrawal < dst_public_withdrawal);
-        assert!(dst_public_withdrawal <= UINT_32_MAX);
-        assert!(dst_public_withdrawal < dst_cancellation);
-        assert!(dst_cancellation <= UINT_32_MAX);
-
-        Self {
+        Self::try_new(
+            src_withdrawal,
+            src_public_withdrawal,
+            src_cancellation,
+            src_public_cancellation,
+            dst_withdrawal,
+            dst_public_withdrawal,
+            dst_cancellation,
             deployed_at,
+        )
+        .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        src_withdrawal: u64,
+        src_public_withdrawal: u64,
+        src_cancellation: u64,
+        src_public_cancellation: u64,
+        dst_withdrawal: u64,
+        dst_public_withdrawal: u64,
+        dst_cancellation: u64,
+        deployed_at: Option<u64>,
+    ) -> crate::Result<Self> {
+        let time_locks = Self {
+            deployed_at: deployed_at.unwrap_or(0),
             src_withdrawal,
             src_public_withdrawal,
             src_cancellation,
@@ -55,7 +65,112 @@ impl TimeLocks {
             dst_withdrawal,
             dst_public_withdrawal,
             dst_cancellation,
+        };
+        time_locks.validate()?;
+        Ok(time_locks)
+    }
+
+    pub fn builder() -> TimeLocksBuilder {
+        TimeLocksBuilder::default()
+    }
+
+    pub fn validate(&self) -> crate::Result<()> {
+        for (name, value) in [
+            ("deployed_at", self.deployed_at),
+            ("src_withdrawal", self.src_withdrawal),
+            ("src_public_withdrawal", self.src_public_withdrawal),
+            ("src_cancellation", self.src_cancellation),
+            ("src_public_cancellation", self.src_public_cancellation),
+            ("dst_withdrawal", self.dst_withdrawal),
+            ("dst_public_withdrawal", self.dst_public_withdrawal),
+            ("dst_cancellation", self.dst_cancellation),
+        ] {
+            if value > UINT_32_MAX {
+                return Err(crate::Error::InvalidTimeLocks(format!(
+                    "{name} does not fit in uint32"
+                )));
+            }
+        }
+
+        for (earlier, earlier_value, later, later_value) in [
+            (
+                "src_withdrawal",
+                self.src_withdrawal,
+                "src_public_withdrawal",
+                self.src_public_withdrawal,
+            ),
+            (
+                "src_public_withdrawal",
+                self.src_public_withdrawal,
+                "src_cancellation",
+                self.src_cancellation,
+            ),
+            (
+                "src_cancellation",
+                self.src_cancellation,
+                "src_public_cancellation",
+                self.src_public_cancellation,
+            ),
+            (
+                "dst_withdrawal",
+                self.dst_withdrawal,
+                "dst_public_withdrawal",
+                self.dst_public_withdrawal,
+            ),
+            (
+                "dst_public_withdrawal",
+                self.dst_public_withdrawal,
+                "dst_cancellation",
+                self.dst_cancellation,
+            ),
+            // otherwise a resolver could claim both sides
+            (
+                "dst_cancellation",
+                self.dst_cancellation,
+                "src_cancellation",
+                self.src_cancellation,
+            ),
+        ] {
+            if earlier_value >= later_value {
+                return Err(crate::Error::InvalidTimeLocks(format!(
+                    "{later} must be after {earlier}"
+                )));
+            }
         }
+
+        Ok(())
+    }
+
+    pub fn src_withdrawal(&self) -> u64 {
+        self.src_withdrawal
+    }
+
+    pub fn src_public_withdrawal(&self) -> u64 {
+        self.src_public_withdrawal
+    }
+
+    pub fn src_cancellation(&self) -> u64 {
+        self.src_cancellation
+    }
+
+    pub fn src_public_cancellation(&self) -> u64 {
+        self.src_public_cancellation
+    }
+
+    pub fn dst_withdrawal(&self) -> u64 {
+        self.dst_withdrawal
+    }
+
+    pub fn dst_public_withdrawal(&self) -> u64 {
+        self.dst_public_withdrawal
+    }
+
+    pub fn dst_cancellation(&self) -> u64 {
+        self.dst_cancellation
+    }
+
+    pub fn deployed_at(&self) -> u64 {
+        self.deployed_at
     }
 
     pub fn from_u256(mut value: U256) -> Self {
@@ -97,6 +212,98 @@ impl TimeLocks {
     }
 }
 
+impl Display for TimeLocks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Time locks")?;
+        for (stage, delay) in [
+            ("src withdrawal", self.src_withdrawal),
+            ("src public withdrawal", self.src_public_withdrawal),
+            ("src cancellation", self.src_cancellation),
+            ("src public cancellation", self.src_public_cancellation),
+            ("dst withdrawal", self.dst_withdrawal),
+            ("dst public withdrawal", self.dst_public_withdrawal),
+        ] {
+            writeln!(f, "  {stage:<24}+{delay}s")?;
+        }
+        write!(f, "  {:<24}+{}s", "dst cancellation", self.dst_cancellation)?;
+        if self.deployed_at != 0 {
+            write!(f, "\n  {:<24}{}", "deployed at", self.deployed_at)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TimeLocksBuilder {
+    src_withdrawal: Option<u64>,
+    src_public_withdrawal: Option<u64>,
+    src_cancellation: Option<u64>,
+    src_public_cancellation: Option<u64>,
+    dst_withdrawal: Option<u64>,
+    dst_public_withdrawal: Option<u64>,
+    dst_cancellation: Option<u64>,
+    deployed_at: Option<u64>,
+}
+
+impl TimeLocksBuilder {
+    pub fn with_src_withdrawal(mut self, delay: u64) -> Self {
+        self.src_withdrawal = Some(delay);
+        self
+    }
+
+    pub fn with_src_public_withdrawal(mut self, delay: u64) -> Self {
+        self.src_public_withdrawal = Some(delay);
+        self
+    }
+
+    pub fn with_src_cancellation(mut self, delay: u64) -> Self {
+        self.src_cancellation = Some(delay);
+        self
+    }
+
+    pub fn with_src_public_cancellation(mut self, delay: u64) -> Self {
+        self.src_public_cancellation = Some(delay);
+        self
+    }
+
+    pub fn with_dst_withdrawal(mut self, delay: u64) -> Self {
+        self.dst_withdrawal = Some(delay);
+        self
+    }
+
+    pub fn with_dst_public_withdrawal(mut self, delay: u64) -> Self {
+        self.dst_public_withdrawal = Some(delay);
+        self
+    }
+
+    pub fn with_dst_cancellation(mut self, delay: u64) -> Self {
+        self.dst_cancellation = Some(delay);
+        self
+    }
+
+    pub fn with_deployed_at(mut self, deployed_at: u64) -> Self {
+        self.deployed_at = Some(deployed_at);
+        self
+    }
+
+    pub fn build(&self) -> crate::Result<TimeLocks> {
+        fn required(value: Option<u64>, name: &str) -> crate::Result<u64> {
+            value.ok_or_else(|| crate::Error::InvalidTimeLocks(format!("{name} is not set")))
+        }
+
+        TimeLocks::try_new(
+            required(self.src_withdrawal, "src_withdrawal")?,
+            required(self.src_public_withdrawal, "src_public_withdrawal")?,
+            required(self.src_cancellation, "src_cancellation")?,
+            required(self.src_public_cancellation, "src_public_cancellation")?,
+            required(self.dst_withdrawal, "dst_withdrawal")?,
+            required(self.dst_public_withdrawal, "dst_public_withdrawal")?,
+            required(self.dst_cancellation, "dst_cancellation")?,
+            self.deployed_at,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,4 +315,50 @@ mod tests {
         let decoded = TimeLocks::from_u256(built);
         assert_eq!(time_locks, decoded);
     }
+
+    #[test]
+    fn test_time_locks_builder() {
+        let built = TimeLocks::builder()
+            .with_src_withdrawal(36)
+            .with_src_public_withdrawal(372)
+            .with_src_cancellation(528)
+            .with_src_public_cancellation(648)
+            .with_dst_withdrawal(60)
+            .with_dst_public_withdrawal(336)
+            .with_dst_cancellation(456)
+            .with_deployed_at(80)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            built,
+            TimeLocks::new(36, 372, 528, 648, 60, 336, 456, Some(80))
+        );
+    }
+
+    #[test]
+    fn test_time_locks_invalid() {
+        let err = TimeLocks::try_new(36, 30, 528, 648, 60, 336, 456, None).unwrap_err();
+        assert!(
+            matches!(err, crate::Error::InvalidTimeLocks(msg) if msg == "src_public_withdrawal must be after src_withdrawal")
+        );
+
+        let err = TimeLocks::try_new(36, 372, 528, 648, 60, 336, 600, None).unwrap_err();
+        assert!(
+            matches!(err, crate::Error::InvalidTimeLocks(msg) if msg == "src_cancellation must be after dst_cancellation")
+        );
+
+        let err = TimeLocks::try_new(36, 372, 528, 648, 60, 336, 456, Some(1 << 32)).unwrap_err();
+        assert!(
+            matches!(err, crate::Error::InvalidTimeLocks(msg) if msg == "deployed_at does not fit in uint32")
+        );
+
+        let err = TimeLocks::builder()
+            .with_src_withdrawal(36)
+            .build()
+            .unwrap_err();
+        assert!(
+            matches!(err, crate::Error::InvalidTimeLocks(msg) if msg == "src_public_withdrawal is not set")
+        );
+    }
 }
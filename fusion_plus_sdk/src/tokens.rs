@@ -0,0 +1,346 @@
+use std::{collections::HashMap, fmt::Display};
+
+use alloy::primitives::U256;
+use serde::Deserialize;
+
+use crate::{
+    chain_id::ChainId,
+    chain_metadata::{CHAINS, ChainKind},
+    constants::NATIVE_CURRENCY,
+    multichain_address::MultichainAddress,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Token {
+    pub chain_id: ChainId,
+    pub address: MultichainAddress,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+// Amount of a token in its smallest unit, displayed as e.g. "1.5 USDC"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenAmount {
+    pub token: Token,
+    pub amount: U256,
+}
+
+// Tokens keyed by chain and address, addresses are stored without their chain id
+#[derive(Clone, Debug, Default)]
+pub struct TokenRegistry {
+    tokens: HashMap<(ChainId, MultichainAddress), Token>,
+}
+
+// Entry of a token list in the https://tokenlists.org format
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TokenListEntry {
+    chain_id: u32,
+    address: String,
+    symbol: String,
+    decimals: u8,
+}
+
+#[derive(Deserialize)]
+struct TokenList {
+    tokens: Vec<TokenListEntry>,
+}
+
+impl TokenAmount {
+    pub fn new(token: Token, amount: U256) -> Self {
+        Self { token, amount }
+    }
+
+    // Parses a decimal value like "1.5", more fractional digits than the token has are
+    // rejected instead of being rounded away
+    pub fn from_decimal(token: Token, value: &str) -> crate::Result<Self> {
+        let invalid = |reason: &str| {
+            crate::Error::InvalidTokenAmount(format!("{value} {}: {reason}", token.symbol))
+        };
+
+        let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(invalid("empty amount"));
+        }
+        if !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid("expected a non negative decimal number"));
+        }
+        if fraction.len() > token.decimals as usize {
+            return Err(invalid(&format!(
+                "token has only {} decimals",
+                token.decimals
+            )));
+        }
+
+        let digits = format!(
+            "{integer}{fraction:0<width$}",
+            width = token.decimals as usize
+        );
+        let amount = U256::from_str_radix(&digits, 10).map_err(|e| invalid(&e.to_string()))?;
+
+        Ok(Self::new(token, amount))
+    }
+
+    // Value in whole tokens, without trailing zeros
+    pub fn to_decimal(&self) -> String {
+        let decimals = self.token.decimals as usize;
+        let digits = format!("{:0>width$}", self.amount, width = decimals + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - decimals);
+        let fraction = fraction.trim_end_matches('0');
+
+        if fraction.is_empty() {
+            integer.to_string()
+        } else {
+            format!("{integer}.{fraction}")
+        }
+    }
+}
+
+impl Display for TokenAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.to_decimal(), self.token.symbol)
+    }
+}
+
+impl TokenRegistry {
+    // Native and wrapped native currencies, USDC and USDT of every chain in the metadata table
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::default();
+
+        for metadata in &CHAINS {
+            let stablecoin_decimals = match metadata.chain_id {
+                // Binance-Peg stablecoins
+                ChainId::Bnb => 18,
+                _ => 6,
+            };
+            let native_decimals = match metadata.kind {
+                ChainKind::Evm => 18,
+                ChainKind::Tron => 6,
+                ChainKind::Solana => 9,
+            };
+            let wrapped_symbol = format!("W{}", metadata.native_symbol);
+
+            if metadata.kind == ChainKind::Evm {
+                registry.insert(Token {
+                    chain_id: metadata.chain_id,
+                    address: NATIVE_CURRENCY,
+                    symbol: metadata.native_symbol.to_string(),
+                    decimals: native_decimals,
+                });
+            }
+
+            for (address, symbol, decimals) in [
+                (
+                    metadata.wrapped_native,
+                    wrapped_symbol.as_str(),
+                    native_decimals,
+                ),
+                (metadata.usdc, "USDC", stablecoin_decimals),
+                (metadata.usdt, "USDT", stablecoin_decimals),
+            ] {
+                if let Some(address) = address {
+                    registry.insert(Token {
+                        chain_id: metadata.chain_id,
+                        address: address.parse().unwrap(),
+                        symbol: symbol.to_string(),
+                        decimals,
+                    });
+                }
+            }
+        }
+
+        registry
+    }
+
+    // Tokens of chains this crate does not support are skipped
+    pub fn from_token_list_json(json: &str) -> crate::Result<Self> {
+        let mut registry = Self::default();
+        registry.extend_from_token_list_json(json)?;
+        Ok(registry)
+    }
+
+    pub fn extend_from_token_list_json(&mut self, json: &str) -> crate::Result<()> {
+        let list: TokenList = serde_json::from_str(json)
+            .map_err(|e| crate::Error::InternalError(format!("invalid token list: {e}")))?;
+
+        for entry in list.tokens {
+            let Ok(chain_id) = ChainId::from_u32(entry.chain_id) else {
+                continue;
+            };
+
+            self.insert(Token {
+                chain_id,
+                address: entry.address.parse()?,
+                symbol: entry.symbol,
+                decimals: entry.decimals,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn insert(&mut self, mut token: Token) {
+        token.address = token.address.without_chain_id();
+        self.tokens.insert((token.chain_id, token.address), token);
+    }
+
+    pub fn get(&self, chain_id: ChainId, address: MultichainAddress) -> Option<&Token> {
+        self.tokens.get(&(chain_id, address.without_chain_id()))
+    }
+
+    // Symbols are matched case insensitively and must be unique on the chain
+    pub fn find_by_symbol(&self, chain_id: ChainId, symbol: &str) -> crate::Result<&Token> {
+        let mut matches = self.tokens.values().filter(|token| {
+            token.chain_id == chain_id && token.symbol.eq_ignore_ascii_case(symbol)
+        });
+
+        match (matches.next(), matches.next()) {
+            (Some(token), None) => Ok(token),
+            (None, _) => Err(crate::Error::TokenNotFound(format!(
+                "{symbol} on {chain_id}"
+            ))),
+            (Some(_), Some(_)) => Err(crate::Error::TokenNotFound(format!(
+                "{symbol} is ambiguous on {chain_id}, use the token address"
+            ))),
+        }
+    }
+
+    // Parses "<value> <symbol or address>", e.g. "1.5 USDC"
+    pub fn parse_amount(&self, chain_id: ChainId, value: &str) -> crate::Result<TokenAmount> {
+        let Some((amount, token)) = value.trim().split_once(char::is_whitespace) else {
+            return Err(crate::Error::InvalidTokenAmount(format!(
+                "{value}: expected \"<amount> <token>\""
+            )));
+        };
+        let token = token.trim();
+
+        let token = match token.parse::<MultichainAddress>() {
+            Ok(address) => self
+                .get(chain_id, address)
+                .ok_or_else(|| crate::Error::TokenNotFound(format!("{token} on {chain_id}")))?,
+            Err(_) => self.find_by_symbol(chain_id, token)?,
+        };
+
+        TokenAmount::from_decimal(token.clone(), amount)
+    }
+
+    pub fn amount(
+        &self,
+        chain_id: ChainId,
+        address: MultichainAddress,
+        amount: U256,
+    ) -> crate::Result<TokenAmount> {
+        let token = self
+            .get(chain_id, address)
+            .ok_or_else(|| crate::Error::TokenNotFound(format!("{address} on {chain_id}")))?;
+
+        Ok(TokenAmount::new(token.clone(), amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        addresses::{usdc, usdt},
+        quote::QuoteRequest,
+    };
+
+    #[test]
+    fn test_parse_and_format() {
+        let registry = TokenRegistry::with_defaults();
+
+        let amount = registry
+            .parse_amount(ChainId::Arbitrum, "1.5 USDC")
+            .unwrap();
+        assert_eq!(amount.token.address, usdc(ChainId::Arbitrum));
+        assert_eq!(amount.amount, U256::from(1_500_000));
+        assert_eq!(amount.to_string(), "1.5 USDC");
+
+        // Binance-Peg USDT has 18 decimals
+        let amount = registry.parse_amount(ChainId::Bnb, "2 usdt").unwrap();
+        assert_eq!(amount.amount, U256::from(2_000_000_000_000_000_000u128));
+
+        let amount = registry
+            .parse_amount(ChainId::Tron, &format!("0.000001 {}", usdt(ChainId::Tron)))
+            .unwrap();
+        assert_eq!(amount.amount, U256::ONE);
+        assert_eq!(amount.to_string(), "0.000001 USDT");
+
+        let amount = registry
+            .amount(
+                ChainId::Ethereum,
+                usdc(ChainId::Ethereum),
+                U256::from(10_000_000),
+            )
+            .unwrap();
+        assert_eq!(amount.to_string(), "10 USDC");
+    }
+
+    #[test]
+    fn test_quote_request_amount() {
+        let amount = TokenRegistry::with_defaults()
+            .parse_amount(ChainId::Arbitrum, "1.5 USDC")
+            .unwrap();
+        let request = QuoteRequest::from_token_amount(
+            amount,
+            ChainId::Optimism,
+            usdc(ChainId::Optimism),
+            true,
+            MultichainAddress::ZERO,
+        );
+
+        assert_eq!(request.src_chain_id, ChainId::Arbitrum);
+        assert_eq!(request.src_token_address, usdc(ChainId::Arbitrum));
+        assert_eq!(request.src_amount, U256::from(1_500_000));
+    }
+
+    #[test]
+    fn test_invalid_amounts() {
+        let registry = TokenRegistry::with_defaults();
+
+        for value in ["1.0000001 USDC", "-1 USDC", "1e6 USDC", ". USDC", "1.5"] {
+            assert!(matches!(
+                registry.parse_amount(ChainId::Arbitrum, value),
+                Err(crate::Error::InvalidTokenAmount(_))
+            ));
+        }
+        assert!(matches!(
+            registry.parse_amount(ChainId::Arbitrum, "1 DOGE"),
+            Err(crate::Error::TokenNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_token_list() {
+        let json = r#"{
+            "name": "test",
+            "tokens": [
+                {
+                    "chainId": 42161,
+                    "address": "0x912ce59144191c1204e64559fe8253a0e49e6548",
+                    "symbol": "ARB",
+                    "name": "Arbitrum",
+                    "decimals": 18
+                },
+                {
+                    "chainId": 5,
+                    "address": "0x912ce59144191c1204e64559fe8253a0e49e6548",
+                    "symbol": "GOERLI",
+                    "decimals": 18
+                }
+            ]
+        }"#;
+
+        let registry = TokenRegistry::from_token_list_json(json).unwrap();
+        let amount = registry
+            .parse_amount(ChainId::Arbitrum, "0.25 ARB")
+            .unwrap();
+        assert_eq!(amount.amount, U256::from(250_000_000_000_000_000u128));
+        assert_eq!(registry.tokens.len(), 1);
+    }
+}
@@ -0,0 +1,321 @@
+use alloy::{
+    hex,
+    primitives::{Address, B256, Bytes, U256},
+    signers::{
+        Signer,
+        k256::sha2::{Digest, Sha256},
+    },
+    sol_types::SolCall,
+};
+use serde_json::{Value, json};
+
+use crate::{multichain_address::MultichainAddress, utils::alloy::ERC20};
+
+// 100 TRX, upper bound of energy burnt by a TRC-20 approve
+pub const DEFAULT_FEE_LIMIT_SUN: u64 = 100_000_000;
+
+// Client of the Tron full node HTTP API (e.g. https://api.trongrid.io).
+// The node's JSON-RPC endpoint answers `eth_call`, so TRC-20 reads also work through
+// `create_read_provider(ChainId::Tron)`, but it can not broadcast transactions:
+// Tron transactions are built by the node and signed over `sha256(raw_data)`
+pub struct TronClient {
+    pub base_url: String,
+    pub api_key: Option<String>,
+}
+
+// Hex form of an address as the HTTP API expects it with `visible: false`
+fn to_tron_hex(address: Address) -> String {
+    format!("41{}", hex::encode(address))
+}
+
+fn node_error(response: &Value) -> crate::Error {
+    // messages of failed calls are hex encoded
+    let message = response
+        .pointer("/result/message")
+        .or_else(|| response.get("message"))
+        .and_then(Value::as_str)
+        .map(|message| {
+            hex::decode(message)
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+                .unwrap_or_else(|| message.to_string())
+        })
+        .unwrap_or_else(|| response.to_string());
+
+    crate::Error::InternalError(format!("tron node error: {message}"))
+}
+
+// Signs the transaction id, after checking that it is the hash of `raw_data_hex`
+async fn sign_transaction(signer: &impl Signer, transaction: &mut Value) -> crate::Result<B256> {
+    let raw_data = transaction
+        .get("raw_data_hex")
+        .and_then(Value::as_str)
+        .and_then(|raw| hex::decode(raw).ok())
+        .ok_or(crate::Error::InternalErrorStr(
+            "tron transaction without raw_data_hex",
+        ))?;
+    let tx_id = B256::from_slice(&Sha256::digest(&raw_data));
+    if transaction.get("txID").and_then(Value::as_str) != Some(&hex::encode(tx_id)) {
+        return Err(crate::Error::InternalErrorStr(
+            "tron transaction id does not match its raw data",
+        ));
+    }
+
+    let signature = signer
+        .sign_hash(&tx_id)
+        .await
+        .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+    transaction["signature"] = json!([hex::encode(signature.as_bytes())]);
+
+    Ok(tx_id)
+}
+
+impl TronClient {
+    pub fn new(base_url: impl Into<String>, api_key: Option<String>) -> Self {
+        TronClient {
+            base_url: base_url.into(),
+            api_key,
+        }
+    }
+
+    // TRON_API_URL and the optional TronGrid key TRON_API_KEY
+    pub fn from_env() -> crate::Result<Self> {
+        let base_url = std::env::var("TRON_API_URL")
+            .map_err(|_| crate::Error::InternalErrorStr("TRON_API_URL is not set"))?;
+        Ok(Self::new(base_url, std::env::var("TRON_API_KEY").ok()))
+    }
+
+    pub async fn trc20_balance_of(
+        &self,
+        token: MultichainAddress,
+        owner: MultichainAddress,
+    ) -> crate::Result<U256> {
+        let result = self
+            .trigger_constant_contract(
+                owner,
+                token,
+                ERC20::balanceOfCall {
+                    owner: owner.as_raw(),
+                },
+            )
+            .await?;
+
+        ERC20::balanceOfCall::abi_decode_returns(&result)
+            .map_err(|e| crate::Error::InternalError(e.to_string()))
+    }
+
+    pub async fn trc20_allowance(
+        &self,
+        token: MultichainAddress,
+        owner: MultichainAddress,
+        spender: MultichainAddress,
+    ) -> crate::Result<U256> {
+        let result = self
+            .trigger_constant_contract(
+                owner,
+                token,
+                ERC20::allowanceCall {
+                    owner: owner.as_raw(),
+                    spender: spender.as_raw(),
+                },
+            )
+            .await?;
+
+        ERC20::allowanceCall::abi_decode_returns(&result)
+            .map_err(|e| crate::Error::InternalError(e.to_string()))
+    }
+
+    // Returns the id of the broadcast transaction, it is not awaited
+    pub async fn trc20_approve(
+        &self,
+        signer: &impl Signer,
+        token: MultichainAddress,
+        spender: MultichainAddress,
+        amount: U256,
+    ) -> crate::Result<B256> {
+        let mut transaction = self
+            .trigger_smart_contract(
+                signer.address().into(),
+                token,
+                ERC20::approveCall {
+                    spender: spender.as_raw(),
+                    value: amount,
+                },
+                DEFAULT_FEE_LIMIT_SUN,
+            )
+            .await?;
+        let tx_id = sign_transaction(signer, &mut transaction).await?;
+
+        let response = self
+            .perform_post("wallet/broadcasttransaction", transaction)
+            .await?;
+        if response.get("result").and_then(Value::as_bool) != Some(true) {
+            return Err(node_error(&response));
+        }
+
+        Ok(tx_id)
+    }
+
+    // Approves `U256::MAX` unless the current allowance already covers `amount`
+    pub async fn ensure_trc20_allowance(
+        &self,
+        signer: &impl Signer,
+        token: MultichainAddress,
+        spender: MultichainAddress,
+        amount: U256,
+    ) -> crate::Result<Option<B256>> {
+        let allowance = self
+            .trc20_allowance(token, signer.address().into(), spender)
+            .await?;
+        if allowance >= amount {
+            return Ok(None);
+        }
+
+        self.trc20_approve(signer, token, spender, U256::MAX)
+            .await
+            .map(Some)
+    }
+
+    async fn trigger_constant_contract(
+        &self,
+        owner: MultichainAddress,
+        contract: MultichainAddress,
+        call: impl SolCall,
+    ) -> crate::Result<Bytes> {
+        let response = self
+            .perform_post(
+                "wallet/triggerconstantcontract",
+                Self::trigger_body(owner, contract, &call),
+            )
+            .await?;
+        if response.pointer("/result/result").and_then(Value::as_bool) != Some(true) {
+            return Err(node_error(&response));
+        }
+
+        response
+            .pointer("/constant_result/0")
+            .and_then(Value::as_str)
+            .and_then(|result| hex::decode(result).ok())
+            .map(Bytes::from)
+            .ok_or(crate::Error::InternalErrorStr(
+                "expected constant_result in tron response",
+            ))
+    }
+
+    async fn trigger_smart_contract(
+        &self,
+        owner: MultichainAddress,
+        contract: MultichainAddress,
+        call: impl SolCall,
+        fee_limit: u64,
+    ) -> crate::Result<Value> {
+        let mut body = Self::trigger_body(owner, contract, &call);
+        body["fee_limit"] = json!(fee_limit);
+
+        let mut response = self
+            .perform_post("wallet/triggersmartcontract", body)
+            .await?;
+        if response.pointer("/result/result").and_then(Value::as_bool) != Some(true) {
+            return Err(node_error(&response));
+        }
+
+        response
+            .get_mut("transaction")
+            .map(Value::take)
+            .ok_or(crate::Error::InternalErrorStr(
+                "expected transaction in tron response",
+            ))
+    }
+
+    fn trigger_body<C: SolCall>(
+        owner: MultichainAddress,
+        contract: MultichainAddress,
+        call: &C,
+    ) -> Value {
+        let data = call.abi_encode();
+
+        json!({
+            "owner_address": to_tron_hex(owner.as_raw()),
+            "contract_address": to_tron_hex(contract.as_raw()),
+            "function_selector": C::SIGNATURE,
+            "parameter": hex::encode(&data[4..]),
+            "call_value": 0,
+            "visible": false,
+        })
+    }
+
+    async fn perform_post(&self, route: &str, body: Value) -> crate::Result<Value> {
+        let url = format!("{}/{route}", self.base_url);
+        let client = reqwest::Client::new();
+        let mut request = client.post(url).json(&body);
+        if let Some(api_key) = &self.api_key {
+            request = request.header("TRON-PRO-API-KEY", api_key);
+        }
+
+        let result = request.send().await?;
+        if result.status().is_success() {
+            Ok(result.json().await?)
+        } else {
+            let error_text = result.text().await?;
+            Err(crate::Error::InternalError(error_text))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{primitives::Signature, signers::local::PrivateKeySigner};
+
+    use super::*;
+
+    #[test]
+    fn test_trigger_body() {
+        let owner: MultichainAddress = "TJLRfJUAHPRxoizJeyYFFZ7nEHit4L9FfE".parse().unwrap();
+        let token: MultichainAddress = "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t".parse().unwrap();
+
+        let body = TronClient::trigger_body(
+            owner,
+            token,
+            &ERC20::balanceOfCall {
+                owner: owner.as_raw(),
+            },
+        );
+
+        assert_eq!(
+            body["owner_address"],
+            "415bc44f18b91f55540d11d612c08e4faad619eb55"
+        );
+        assert_eq!(body["function_selector"], "balanceOf(address)");
+        assert_eq!(
+            body["parameter"],
+            "0000000000000000000000005bc44f18b91f55540d11d612c08e4faad619eb55"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sign_transaction() {
+        let signer: PrivateKeySigner =
+            "0x0123456789012345678901234567890123456789012345678901234567890123"
+                .parse()
+                .unwrap();
+        let raw_data = hex::decode("0a0207902208e1b9de559665c6714080c49789bb2c").unwrap();
+        let tx_id = hex::encode(Sha256::digest(&raw_data));
+
+        let mut transaction = json!({
+            "txID": "00".repeat(32),
+            "raw_data_hex": hex::encode(&raw_data),
+        });
+        assert!(sign_transaction(&signer, &mut transaction).await.is_err());
+
+        transaction["txID"] = json!(tx_id);
+        let signed = sign_transaction(&signer, &mut transaction).await.unwrap();
+        assert_eq!(hex::encode(signed), tx_id);
+
+        let signature = hex::decode(transaction["signature"][0].as_str().unwrap()).unwrap();
+        let signature = Signature::try_from(signature.as_slice()).unwrap();
+        assert_eq!(
+            signature.recover_address_from_prehash(&signed).unwrap(),
+            signer.address()
+        );
+    }
+}
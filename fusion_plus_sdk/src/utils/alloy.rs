@@ -1,11 +1,13 @@
 use alloy::{
-    primitives::{B256, Bytes, U256},
-    providers::{Provider, ProviderBuilder},
+    eips::BlockNumberOrTag,
+    primitives::{Address, B256, Bytes, U256},
+    providers::{Provider, ProviderBuilder, WalletProvider},
     signers::local::PrivateKeySigner,
     sol,
+    transports::http::reqwest::Url,
 };
 
-use crate::chain_id::ChainId;
+use crate::{chain_id::ChainId, chain_metadata::ChainKind};
 
 pub trait CustomAlloy {
     fn to_u256(&self) -> U256;
@@ -49,17 +51,46 @@ impl CustomAlloy for Bytes {
     }
 }
 
-pub fn create_provider(chain_id: ChainId, wallet: PrivateKeySigner) -> impl Provider {
-    let var = match chain_id {
-        ChainId::Ethereum => "ETH_RPC_URL",
-        ChainId::Optimism => "OPTIMISM_RPC_URL",
-        ChainId::Arbitrum => "ARBITRUM_RPC_URL",
-        ChainId::Tron => "TRON_RPC_URL",
-    };
+fn rpc_url(chain_id: ChainId) -> crate::Result<Url> {
+    let metadata = chain_id.metadata();
+    if metadata.kind == ChainKind::Solana {
+        return Err(crate::Error::UnsupportedChainId(chain_id.into()));
+    }
+
+    let url = std::env::var(metadata.rpc_env_var)
+        .map_err(|_| crate::Error::InternalError(format!("{} is not set", metadata.rpc_env_var)))?;
+    url.parse().map_err(|err| {
+        crate::Error::InternalError(format!(
+            "{} is not a valid url: {err}",
+            metadata.rpc_env_var
+        ))
+    })
+}
+
+pub fn create_provider(
+    chain_id: ChainId,
+    wallet: PrivateKeySigner,
+) -> crate::Result<impl Provider + WalletProvider> {
+    Ok(ProviderBuilder::new()
+        .wallet(wallet)
+        .connect_http(rpc_url(chain_id)?))
+}
+
+pub fn create_read_provider(chain_id: ChainId) -> crate::Result<impl Provider> {
+    Ok(ProviderBuilder::new().connect_http(rpc_url(chain_id)?))
+}
 
-    let url = std::env::var(var).unwrap().parse().unwrap();
+// Returns the timestamp and base fee of the latest block, base fee is zero on chains without EIP-1559
+pub async fn get_latest_block_base_fee(provider: &impl Provider) -> crate::Result<(u64, U256)> {
+    let block = provider
+        .get_block_by_number(BlockNumberOrTag::Latest)
+        .await?
+        .ok_or(crate::Error::InternalErrorStr("latest block not found"))?;
 
-    ProviderBuilder::new().wallet(wallet).connect_http(url)
+    Ok((
+        block.header.timestamp,
+        U256::from(block.header.base_fee_per_gas.unwrap_or_default()),
+    ))
 }
 
 sol! {
@@ -71,3 +102,46 @@ sol! {
         function approve(address spender, uint256 value) returns (bool);
     }
 }
+
+// Approves `U256::MAX` unless the current allowance already covers `amount`
+pub async fn ensure_allowance(
+    provider: &impl Provider,
+    token: Address,
+    owner: Address,
+    spender: Address,
+    amount: U256,
+) -> crate::Result<()> {
+    let token = ERC20::new(token, provider);
+    let allowance = token
+        .allowance(owner, spender)
+        .call()
+        .await
+        .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+    if allowance >= amount {
+        return Ok(());
+    }
+
+    token
+        .approve(spender, U256::MAX)
+        .send()
+        .await
+        .map_err(|e| crate::Error::InternalError(e.to_string()))?
+        .watch()
+        .await
+        .map_err(|e| crate::Error::InternalError(e.to_string()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solana_has_no_provider() {
+        assert!(matches!(
+            create_read_provider(ChainId::Solana),
+            Err(crate::Error::UnsupportedChainId(501))
+        ));
+    }
+}
@@ -7,7 +7,7 @@ pub struct Bps {
     value: u64, // Basis points
 }
 
-const FEE_BASE: u64 = 100_000;
+pub const FEE_BASE: u64 = 100_000;
 const BPS_BASE: u64 = 10_000;
 const BPS_TO_RATIO_NUMERATOR: u64 = FEE_BASE / BPS_BASE;
 
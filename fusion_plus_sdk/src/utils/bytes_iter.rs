@@ -9,11 +9,19 @@ pub enum Side {
 
 pub struct BytesIter {
     bytes: Bytes,
+    // name of the decoded value, used for error reporting
+    field: &'static str,
+    // bytes consumed from the front, i.e. offset of `bytes` in the original input
+    offset: usize,
 }
 
 impl BytesIter {
-    pub fn new(bytes: Bytes) -> Self {
-        Self { bytes }
+    pub fn new(bytes: Bytes, field: &'static str) -> Self {
+        Self {
+            bytes,
+            field,
+            offset: 0,
+        }
     }
 
     pub fn rest(self) -> Bytes {
@@ -24,19 +32,39 @@ impl BytesIter {
         self.bytes.is_empty()
     }
 
-    pub fn next_byte(&mut self, side: Side) -> Bytes {
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn error(&self, reason: impl Into<String>) -> crate::Error {
+        crate::Error::Decode {
+            field: self.field,
+            offset: self.offset,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn next_byte(&mut self, side: Side) -> crate::Result<Bytes> {
         self.next_bytes(1, side)
     }
 
-    pub fn next_bytes(&mut self, n: usize, side: Side) -> Bytes {
+    pub fn next_bytes(&mut self, n: usize, side: Side) -> crate::Result<Bytes> {
         let cnt = n;
         if self.bytes.len() < cnt {
-            panic!("Can not consume {n} bytes, have only {}", self.bytes.len());
+            return Err(self.error(format!(
+                "can not consume {n} bytes, have only {}",
+                self.bytes.len()
+            )));
         }
 
         let (chunk, rest) = match side {
             Side::Front => {
                 let (chunk, rest) = self.bytes.split_at(cnt);
+                self.offset += cnt;
                 (chunk, rest)
             }
             Side::Back => {
@@ -47,44 +75,70 @@ impl BytesIter {
 
         let chunk = chunk.to_vec().into();
         self.bytes = rest.to_vec().into();
-        chunk
+        Ok(chunk)
     }
 
-    pub fn next_address(&mut self, side: Side) -> Address {
-        let val = self.next_bytes(20, side);
-        Address::from_slice(&val)
+    pub fn next_address(&mut self, side: Side) -> crate::Result<Address> {
+        let val = self.next_bytes(20, side)?;
+        Ok(Address::from_slice(&val))
     }
 
-    pub fn next_uint8(&mut self, side: Side) -> U256 {
-        self.next_bytes(1, side).to_u256()
+    pub fn next_uint8(&mut self, side: Side) -> crate::Result<U256> {
+        Ok(self.next_bytes(1, side)?.to_u256())
     }
 
-    pub fn next_uint16(&mut self, side: Side) -> U256 {
-        self.next_bytes(2, side).to_u256()
+    pub fn next_uint16(&mut self, side: Side) -> crate::Result<U256> {
+        Ok(self.next_bytes(2, side)?.to_u256())
     }
 
-    pub fn next_uint24(&mut self, side: Side) -> U256 {
-        self.next_bytes(3, side).to_u256()
+    pub fn next_uint24(&mut self, side: Side) -> crate::Result<U256> {
+        Ok(self.next_bytes(3, side)?.to_u256())
     }
 
-    pub fn next_uint32(&mut self, side: Side) -> U256 {
-        self.next_bytes(4, side).to_u256()
+    pub fn next_uint32(&mut self, side: Side) -> crate::Result<U256> {
+        Ok(self.next_bytes(4, side)?.to_u256())
     }
 
-    pub fn next_uint128(&mut self, side: Side) -> U256 {
-        self.next_bytes(16, side).to_u256()
+    pub fn next_uint128(&mut self, side: Side) -> crate::Result<U256> {
+        Ok(self.next_bytes(16, side)?.to_u256())
     }
 
-    pub fn next_uint160(&mut self, side: Side) -> U256 {
-        self.next_bytes(20, side).to_u256()
+    pub fn next_uint160(&mut self, side: Side) -> crate::Result<U256> {
+        Ok(self.next_bytes(20, side)?.to_u256())
     }
 
-    pub fn next_uint256(&mut self, side: Side) -> U256 {
-        self.next_bytes(32, side).to_u256()
+    pub fn next_uint256(&mut self, side: Side) -> crate::Result<U256> {
+        Ok(self.next_bytes(32, side)?.to_u256())
     }
 
-    pub fn first_address(value: Bytes) -> Address {
-        let mut value = Self::new(value);
+    pub fn first_address(value: Bytes, field: &'static str) -> crate::Result<Address> {
+        let mut value = Self::new(value, field);
         value.next_address(Side::Front)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_out_of_bounds() {
+        let mut iter = BytesIter::new(Bytes::from(vec![1, 2, 3, 4, 5]), "test");
+
+        assert_eq!(iter.next_uint16(Side::Front).unwrap(), U256::from(0x0102));
+        assert_eq!(iter.next_uint8(Side::Back).unwrap(), U256::from(5));
+
+        let err = iter.next_uint32(Side::Front).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::Decode {
+                field: "test",
+                offset: 2,
+                ..
+            }
+        ));
+
+        // failed reads do not consume anything
+        assert_eq!(iter.rest(), Bytes::from(vec![3, 4]));
+    }
+}